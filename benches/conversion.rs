@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+use std::num::NonZeroI8;
+
+use chrono::prelude::*;
+use chrono::Duration;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use heca_lib::prelude::*;
+use heca_lib::{BatchConverter, HebrewDate, HebrewYear, HebrewYearCache};
+
+fn hebrew_year(c: &mut Criterion) {
+    c.bench_function("HebrewYear::new", |b| {
+        b.iter(|| HebrewYear::new(black_box(5780)).unwrap())
+    });
+    let cache = HebrewYearCache::default();
+    c.bench_function("HebrewYearCache::get", |b| {
+        b.iter(|| cache.get(black_box(5780)).unwrap())
+    });
+}
+
+fn gregorian_to_hebrew(c: &mut Criterion) {
+    let times = (0..1000)
+        .map(|i| Utc.ymd(2020, 1, 1).and_hms(12, 0, 0) + Duration::hours(i * 97))
+        .collect::<Vec<_>>();
+    c.bench_function("HebrewDate::try_from(DateTime<Utc>) x1000", |b| {
+        b.iter(|| {
+            for time in times.iter() {
+                black_box(HebrewDate::try_from(*time).unwrap());
+            }
+        })
+    });
+    c.bench_function("BatchConverter::convert_gregorian_slice x1000", |b| {
+        b.iter(|| black_box(BatchConverter::new().convert_gregorian_slice(&times)))
+    });
+}
+
+fn hebrew_to_gregorian(c: &mut Criterion) {
+    let dates = (0..1000)
+        .map(|i| {
+            HebrewDate::from_ymd(5700 + i, HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    c.bench_function("DateTime<Utc>::from(HebrewDate) x1000", |b| {
+        b.iter(|| {
+            for date in dates.iter() {
+                black_box(DateTime::<Utc>::from(*date));
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    hebrew_year,
+    gregorian_to_hebrew,
+    hebrew_to_gregorian
+);
+criterion_main!(benches);
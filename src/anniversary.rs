@@ -0,0 +1,416 @@
+//! Finding the anniversaries of events in later years.
+//!
+//! A Hebrew anniversary isn't always on the same day and month, as a year might not have the 30th
+//! of Cheshvan or Kislev, or might have two Adars (or none). The rules for this are different for a
+//! yahrzeit and for a birthday, and there are different customs for a yahrzeit.
+//!
+//! # Examples:
+//!
+//! ```
+//! # #[cfg(feature = "chrono")]
+//! # fn main() -> Result<(), heca_lib::prelude::ConversionError> {
+//! use chrono::NaiveDate;
+//! use heca_lib::anniversary::{bar_mitzvah, bar_mitzvah_shabbos, birthday};
+//! use heca_lib::prelude::*;
+//! use heca_lib::{hebrew_date, HebrewDate};
+//!
+//! // Born on the evening of March 3, 2008, which was already the 27th of Adar 1.
+//! let birth = HebrewDate::from_naive_date(NaiveDate::from_ymd(2008, 3, 3), true)?;
+//! assert_eq!(birth, hebrew_date!(5768, Adar1, 27));
+//! assert_eq!(birthday(birth, 5780)?, hebrew_date!(5780, Adar, 27));
+//! assert_eq!(bar_mitzvah(birth)?, hebrew_date!(5781, Adar, 27));
+//!
+//! let shabbos = bar_mitzvah_shabbos(birth, Location::Chul)?;
+//! assert_eq!(shabbos.day(), hebrew_date!(5781, Adar, 29));
+//! assert_eq!(shabbos.name(), TorahReading::Shabbos(Parsha::VayakhelPikudei));
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "chrono"))]
+//! # fn main() {}
+//! ```
+
+use core::num::NonZeroI8;
+
+use smallvec::{smallvec, SmallVec};
+
+use crate::convert::{HebrewDate, HebrewYear};
+use crate::prelude::*;
+
+/// Returns the yahrzeit of a death in a given year.
+///
+/// There are usually one or two dates (two only if the death was in Adar of a regular year, the
+/// yahrzeit is in a leap year and `rules.leap_adar` is `YahrzeitAdar::Both`).
+///
+/// # Arguments
+///
+/// `death` - The day of the death.
+///
+/// `year` - The year to find the yahrzeit in.
+///
+/// `rules` - Which customs to follow. See `YahrzeitRules`.
+///
+/// # Error Values
+/// * `YearTooSmall` - The year isn't after the year of the death.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::anniversary::yahrzeit;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::prelude::*;
+///
+/// // 5779 is a leap year.
+/// let death = hebrew_date!(5778, Adar, 7);
+/// assert_eq!(
+///     yahrzeit(death, 5779, YahrzeitRules::ashkenazi())?.as_slice(),
+///     &[hebrew_date!(5779, Adar1, 7)]
+/// );
+/// assert_eq!(
+///     yahrzeit(death, 5779, YahrzeitRules::sephardi())?.as_slice(),
+///     &[hebrew_date!(5779, Adar2, 7)]
+/// );
+/// let both = YahrzeitRules {
+///     leap_adar: YahrzeitAdar::Both,
+///     ..YahrzeitRules::default()
+/// };
+/// assert_eq!(
+///     yahrzeit(death, 5779, both)?.as_slice(),
+///     &[hebrew_date!(5779, Adar1, 7), hebrew_date!(5779, Adar2, 7)]
+/// );
+///
+/// // Cheshvan had 30 days in 5780, so the yahrzeit is on Rosh Chodesh Kislev when there's no 30th.
+/// let death = hebrew_date!(5779, Cheshvan, 30);
+/// assert_eq!(
+///     yahrzeit(death, 5781, YahrzeitRules::ashkenazi())?.as_slice(),
+///     &[hebrew_date!(5781, Kislev, 1)]
+/// );
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn yahrzeit(
+    death: HebrewDate,
+    year: u64,
+    rules: YahrzeitRules,
+) -> Result<SmallVec<[HebrewDate; 2]>, ConversionError> {
+    if year <= death.year() {
+        return Err(ConversionError::YearTooSmall);
+    }
+    let hebrew_year = HebrewYear::new(year)?;
+    let day = death.day();
+    let months: SmallVec<[HebrewMonth; 2]> = match (death.month(), hebrew_year.is_leap_year()) {
+        (HebrewMonth::Adar, true) => match rules.leap_adar {
+            YahrzeitAdar::Adar1 => smallvec![HebrewMonth::Adar1],
+            YahrzeitAdar::Adar2 => smallvec![HebrewMonth::Adar2],
+            YahrzeitAdar::Both => smallvec![HebrewMonth::Adar1, HebrewMonth::Adar2],
+        },
+        (HebrewMonth::Adar1, false) if day.get() == 30 => {
+            return Ok(smallvec![
+                hebrew_year.get_hebrew_date(HebrewMonth::Shvat, day)?
+            ]);
+        }
+        (HebrewMonth::Adar1, false) | (HebrewMonth::Adar2, false) => smallvec![HebrewMonth::Adar],
+        (month, _) => smallvec![month],
+    };
+    months
+        .into_iter()
+        .map(|month| {
+            if day.get() as u8 <= hebrew_year.sched[month as usize] {
+                return hebrew_year.get_hebrew_date(month, day);
+            }
+            //Only Cheshvan and Kislev can get here, as no other month can lose its 30th day.
+            let next_month = match rules.missing_thirtieth {
+                MissingThirtieth::LastDay => false,
+                MissingThirtieth::NextMonth => true,
+                MissingThirtieth::FirstAnniversary => {
+                    HebrewYear::new(death.year() + 1)?.sched[month as usize] == 30
+                }
+            };
+            let last_day = hebrew_year.get_hebrew_date(month, NonZeroI8::new(29).unwrap())?;
+            Ok(if next_month {
+                last_day.checked_add_days(1).unwrap()
+            } else {
+                last_day
+            })
+        })
+        .collect()
+}
+
+/// Returns the Hebrew birthday of someone born on a given day, in a given year.
+///
+/// Someone born in Adar of a regular year has their birthday in Adar 2 of a leap year, and someone
+/// born in Adar 1 or Adar 2 has their birthday in Adar of a regular year. If the month doesn't have
+/// a 30th day, the birthday of someone born on the 30th is on the 1st of the next month.
+///
+/// # Error Values
+/// * `YearTooSmall` - The year is before the year of the birth.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::anniversary::birthday;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!(birthday(hebrew_date!(5778, Adar, 14), 5779)?, hebrew_date!(5779, Adar2, 14));
+/// // Cheshvan only had 29 days in 5781.
+/// assert_eq!(birthday(hebrew_date!(5780, Cheshvan, 30), 5781)?, hebrew_date!(5781, Kislev, 1));
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn birthday(birth: HebrewDate, year: u64) -> Result<HebrewDate, ConversionError> {
+    if year < birth.year() {
+        return Err(ConversionError::YearTooSmall);
+    }
+    let policy = ArithmeticPolicy {
+        day_overflow: DayOverflow::Carry,
+        leap_adar: LeapAdar::Adar2,
+    };
+    birth.add_years((year - birth.year()) as i64, policy)
+}
+
+/// Returns the day of the Bar Mitzvah (the 13th birthday) of a boy born on a given day.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::anniversary::bar_mitzvah;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!(bar_mitzvah(hebrew_date!(5766, Adar, 1))?, hebrew_date!(5779, Adar2, 1));
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn bar_mitzvah(birth: HebrewDate) -> Result<HebrewDate, ConversionError> {
+    birthday(birth, birth.year() + 13)
+}
+
+/// Returns the day of the Bat Mitzvah (the 12th birthday) of a girl born on a given day.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::anniversary::bat_mitzvah;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!(bat_mitzvah(hebrew_date!(5767, Adar, 1))?, hebrew_date!(5779, Adar2, 1));
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn bat_mitzvah(birth: HebrewDate) -> Result<HebrewDate, ConversionError> {
+    birthday(birth, birth.year() + 12)
+}
+
+/// Returns the first Shabbos on or after the Bar Mitzvah of a boy born on a given day, along with
+/// what's read on it (usually the parsha, but it might be a Yom Tov or Chol HaMoed).
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::anniversary::bar_mitzvah_shabbos;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::prelude::*;
+///
+/// // The Bar Mitzvah is on Wednesday, the 24th of Tishrei 5780.
+/// let shabbos = bar_mitzvah_shabbos(hebrew_date!(5767, Tishrei, 24), Location::Chul)?;
+/// assert_eq!(shabbos.day(), hebrew_date!(5780, Tishrei, 27));
+/// assert_eq!(shabbos.name(), TorahReading::Shabbos(Parsha::Bereishis));
+///
+/// // In Israel, the 22nd of Nissan 5778 is a regular Shabbos, but in the Diaspora it's the last
+/// // day of Pesach.
+/// let birth = hebrew_date!(5765, Nissan, 17);
+/// let shabbos = bar_mitzvah_shabbos(birth, Location::Israel)?;
+/// assert_eq!(shabbos.name(), TorahReading::Shabbos(Parsha::Shemini));
+/// let shabbos = bar_mitzvah_shabbos(birth, Location::Chul)?;
+/// assert_eq!(shabbos.name(), TorahReading::YomTov(YomTov::Pesach8));
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn bar_mitzvah_shabbos(
+    birth: HebrewDate,
+    location: Location,
+) -> Result<TorahReadingDay, ConversionError> {
+    let bar_mitzvah = bar_mitzvah(birth)?;
+    let days_until_shabbos = (Day::Shabbos as i64 - bar_mitzvah.weekday() as i64 + 7) % 7;
+    //Moving forward can't fail.
+    let shabbos = bar_mitzvah.checked_add_days(days_until_shabbos).unwrap();
+    //Every Shabbos has either a parsha or a Yom Tov reading.
+    Ok(*HebrewYear::new(shabbos.year())?
+        .get_holidays(
+            location,
+            &[TorahReadingType::Shabbos, TorahReadingType::YomTov],
+        )
+        .iter()
+        .find(|x| x.day() == shabbos)
+        .unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_RULES: [YahrzeitRules; 6] = [
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::LastDay,
+            leap_adar: YahrzeitAdar::Adar1,
+        },
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::NextMonth,
+            leap_adar: YahrzeitAdar::Adar2,
+        },
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::FirstAnniversary,
+            leap_adar: YahrzeitAdar::Both,
+        },
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::LastDay,
+            leap_adar: YahrzeitAdar::Both,
+        },
+        YahrzeitRules::ashkenazi(),
+        YahrzeitRules::sephardi(),
+    ];
+
+    #[test]
+    fn yahrzeits_are_in_the_right_place() {
+        for death_year in (5700..5800).step_by(3) {
+            for death in HebrewYear::new(death_year).unwrap().days() {
+                for year in death_year + 1..death_year + 5 {
+                    let hebrew_year = HebrewYear::new(year).unwrap();
+                    for rules in ALL_RULES.iter() {
+                        let dates = yahrzeit(death, year, *rules).unwrap();
+                        assert!(!dates.is_empty() && dates.len() <= 2);
+                        for date in dates.iter() {
+                            assert_eq!(date.year(), year);
+                        }
+                        let month = dates[0].month();
+                        let same_month = month == death.month()
+                            || (month == HebrewMonth::Adar
+                                && (death.month() == HebrewMonth::Adar1
+                                    || death.month() == HebrewMonth::Adar2))
+                            || (death.month() == HebrewMonth::Adar
+                                && (month == HebrewMonth::Adar1 || month == HebrewMonth::Adar2));
+                        if death.day().get() < 30 {
+                            assert!(same_month, "{:?} {:?}", death, dates);
+                            assert_eq!(dates[0].day(), death.day());
+                        }
+                        if dates.len() == 2 {
+                            assert_eq!(death.month(), HebrewMonth::Adar);
+                            assert!(hebrew_year.is_leap_year());
+                            assert_eq!(rules.leap_adar, YahrzeitAdar::Both);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn thirtieth_of_short_months() {
+        //Cheshvan had 30 days in 5779 and 5780, and 29 days in 5781 and 5782.
+        for (year, days) in [(5779, 30), (5780, 30), (5781, 29), (5782, 29)].iter() {
+            assert_eq!(
+                HebrewYear::new(*year).unwrap().sched[HebrewMonth::Cheshvan as usize],
+                *days
+            );
+        }
+        let rules = |missing_thirtieth| YahrzeitRules {
+            missing_thirtieth,
+            ..YahrzeitRules::default()
+        };
+        let first_anniversary = rules(MissingThirtieth::FirstAnniversary);
+        let last_day = rules(MissingThirtieth::LastDay);
+        let next_month = rules(MissingThirtieth::NextMonth);
+
+        let death = hebrew_date!(5779, Cheshvan, 30);
+        assert_eq!(
+            yahrzeit(death, 5780, first_anniversary).unwrap()[0],
+            hebrew_date!(5780, Cheshvan, 30)
+        );
+        assert_eq!(
+            yahrzeit(death, 5781, first_anniversary).unwrap()[0],
+            hebrew_date!(5781, Kislev, 1)
+        );
+        assert_eq!(
+            yahrzeit(death, 5781, last_day).unwrap()[0],
+            hebrew_date!(5781, Cheshvan, 29)
+        );
+        assert_eq!(
+            yahrzeit(death, 5781, next_month).unwrap()[0],
+            hebrew_date!(5781, Kislev, 1)
+        );
+
+        //The year after this death didn't have a 30th of Cheshvan.
+        let death = hebrew_date!(5780, Cheshvan, 30);
+        assert_eq!(
+            yahrzeit(death, 5782, first_anniversary).unwrap()[0],
+            hebrew_date!(5782, Cheshvan, 29)
+        );
+        assert_eq!(
+            yahrzeit(death, 5782, next_month).unwrap()[0],
+            hebrew_date!(5782, Kislev, 1)
+        );
+    }
+
+    #[test]
+    fn birthdays() {
+        for birth_year in (5700..5800).step_by(7) {
+            for birth in HebrewYear::new(birth_year).unwrap().days() {
+                for year in birth_year..birth_year + 4 {
+                    let date = birthday(birth, year).unwrap();
+                    assert_eq!(date.year(), year);
+                    if year == birth_year {
+                        assert_eq!(date, birth);
+                    }
+                    if date.day().get() == 1 && birth.day().get() == 30 {
+                        continue;
+                    }
+                    assert_eq!(date.day(), birth.day());
+                    match (birth.month(), HebrewYear::new(year).unwrap().is_leap_year()) {
+                        (HebrewMonth::Adar, true) => assert_eq!(date.month(), HebrewMonth::Adar2),
+                        (HebrewMonth::Adar1, false) | (HebrewMonth::Adar2, false) => {
+                            assert_eq!(date.month(), HebrewMonth::Adar)
+                        }
+                        (month, _) => assert_eq!(date.month(), month),
+                    }
+                }
+                let shabbos = bar_mitzvah_shabbos(birth, Location::Chul).unwrap();
+                assert_eq!(shabbos.day().weekday(), Day::Shabbos);
+                let days = bar_mitzvah(birth).unwrap().days_until(shabbos.day());
+                assert!((0..7).contains(&days));
+                assert_eq!(
+                    bat_mitzvah(birth).unwrap(),
+                    birthday(birth, birth_year + 12).unwrap()
+                );
+            }
+        }
+        assert_eq!(
+            birthday(hebrew_date!(5779, Adar1, 30), 5780),
+            Ok(hebrew_date!(5780, Nissan, 1))
+        );
+        assert_eq!(
+            birthday(hebrew_date!(5779, Adar1, 1), 5778),
+            Err(ConversionError::YearTooSmall)
+        );
+    }
+
+    #[test]
+    fn adar_in_regular_years() {
+        let rules = YahrzeitRules::default();
+        assert_eq!(
+            yahrzeit(hebrew_date!(5779, Adar1, 30), 5780, rules).unwrap()[0],
+            hebrew_date!(5780, Shvat, 30)
+        );
+        assert_eq!(
+            yahrzeit(hebrew_date!(5779, Adar1, 14), 5780, rules).unwrap()[0],
+            hebrew_date!(5780, Adar, 14)
+        );
+        assert_eq!(
+            yahrzeit(hebrew_date!(5779, Adar2, 29), 5780, rules).unwrap()[0],
+            hebrew_date!(5780, Adar, 29)
+        );
+        assert_eq!(
+            yahrzeit(hebrew_date!(5779, Adar1, 30), 5782, rules).unwrap()[0],
+            hebrew_date!(5782, Adar1, 30)
+        );
+        assert_eq!(
+            yahrzeit(hebrew_date!(5779, Adar1, 1), 5779, rules),
+            Err(ConversionError::YearTooSmall)
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Finding when to light candles and make havdalah for Shabbos and Yom Tov.
+//!
+//! Candles are lit a given amount of minutes before sunset on the evening before Shabbos or Yom
+//! Tov. When a day of Yom Tov comes right after Shabbos or another day of Yom Tov, the candles
+//! can only be lit after the first day is over, so they're lit after tzeis. Shabbos after Yom Tov
+//! is the exception, as the candles have to be lit before Shabbos starts. Havdalah is made after
+//! the last day of Shabbos and Yom Tov is over.
+//!
+//! Sunset is calculated at sea level, as is usual for candle lighting.
+
+use alloc::vec::Vec;
+
+use chrono::prelude::*;
+use chrono::Duration;
+
+use crate::convert::sun::{sunset, time_at_zenith};
+use crate::convert::{HebrewDate, HebrewDateRange, HebrewYear};
+use crate::holidays::get_yt_list;
+use crate::prelude::*;
+use crate::zmanim::Coordinates;
+
+/// When Shabbos and Yom Tov end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayEnd {
+    /// When the sun is the given amount of degrees below the horizon.
+    Degrees(f64),
+    /// The given amount of minutes after sunset.
+    Minutes(i64),
+}
+
+/// When to light candles and make havdalah.
+///
+/// The default is 18 minutes before sunset, and when the sun is 8.5 degrees below the horizon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleLightingOptions {
+    pub minutes_before_sunset: i64,
+    pub day_end: DayEnd,
+}
+
+impl Default for CandleLightingOptions {
+    fn default() -> Self {
+        CandleLightingOptions {
+            minutes_before_sunset: 18,
+            day_end: DayEnd::Degrees(8.5),
+        }
+    }
+}
+
+/// What happens at a CandleEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleEventKind {
+    /// Lighting candles before sunset.
+    CandleLighting,
+    /// Lighting candles after tzeis, from an existing flame, for a day of Yom Tov that comes right
+    /// after Shabbos or another day of Yom Tov.
+    CandleLightingAfterTzeis,
+    Havdalah,
+}
+
+/// A time to light candles or to make havdalah.
+#[derive(Debug, Clone)]
+pub struct CandleEvent<Tz: TimeZone> {
+    pub kind: CandleEventKind,
+    /// The day of Shabbos or Yom Tov which starts after candle lighting, or which ends at havdalah.
+    pub day: HebrewDate,
+    /// The Yom Tov on that day, or None if it's just Shabbos.
+    pub yom_tov: Option<YomTov>,
+    pub time: DateTime<Tz>,
+}
+
+// Returns the Yom Tov on a given day, if it's a day on which melacha is forbidden.
+fn yom_tov_on(date: HebrewDate, location: Location) -> Option<YomTov> {
+    //A HebrewDate's year always exists.
+    get_yt_list(HebrewYear::new(date.year()).unwrap(), location)
+        .iter()
+        .find(|x| x.day() == date)
+        .and_then(|x| match x.name() {
+            TorahReading::YomTov(yom_tov) => Some(yom_tov),
+            _ => None,
+        })
+        .filter(|yom_tov| match yom_tov {
+            YomTov::Sukkos2 | YomTov::Pesach2 => location == Location::Chul,
+            YomTov::Sukkos3
+            | YomTov::Sukkos4
+            | YomTov::Sukkos5
+            | YomTov::Sukkos6
+            | YomTov::Sukkos7
+            | YomTov::Pesach3
+            | YomTov::Pesach4
+            | YomTov::Pesach5
+            | YomTov::Pesach6 => false,
+            _ => true,
+        })
+}
+
+fn is_rest_day(date: HebrewDate, location: Location) -> bool {
+    date.weekday() == Day::Shabbos || yom_tov_on(date, location).is_some()
+}
+
+/// Returns every candle lighting and havdalah for the days of Shabbos and Yom Tov in a range of
+/// days, in order.
+///
+/// The candle lighting of the first day in the range is on the evening before it, and the havdalah
+/// of the last day in the range is on its evening.
+///
+/// # Arguments
+///
+/// `days` - The days to find Shabbos and Yom Tov in.
+///
+/// `location` - Whether this is in Israel or in the Diaspora, which decides which days are Yom
+/// Tov.
+///
+/// `coordinates` - Where to calculate sunset for. The elevation isn't used.
+///
+/// `tz` - The time zone to return the times in.
+///
+/// `options` - How long before sunset to light candles, and when Shabbos and Yom Tov end.
+///
+/// # Error Values
+/// * `NoSunset` - The sun doesn't set (or doesn't get low enough for the end of Shabbos or Yom
+///   Tov) on one of the days.
+/// * `YearTooLarge` - One of the days is after the last date chrono supports.
+///
+/// # Examples:
+///
+/// ```
+/// use chrono::prelude::*;
+/// use heca_lib::candle_lighting::{candle_lighting, CandleEventKind, CandleLightingOptions};
+/// use heca_lib::prelude::*;
+/// use heca_lib::zmanim::Coordinates;
+/// use heca_lib::{hebrew_date, HebrewDateRange};
+///
+/// let new_york = Coordinates {
+///     latitude: 40.7128,
+///     longitude: -74.006,
+///     elevation: 0.0,
+/// };
+/// let new_york_time = FixedOffset::west(4 * 3600);
+/// // Shavuos 5780 was on Friday and Shabbos.
+/// let days = HebrewDateRange::new(hebrew_date!(5780, Sivan, 1), hebrew_date!(5780, Sivan, 7));
+/// let events = candle_lighting(days, Location::Chul, new_york, &new_york_time, CandleLightingOptions::default())?;
+/// let events = events
+///     .iter()
+///     .map(|x| (x.kind, x.time.format("%a %H:%M").to_string()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     events,
+///     vec![
+///         (CandleEventKind::CandleLighting, "Thu 20:00".to_string()),
+///         (CandleEventKind::CandleLighting, "Fri 20:01".to_string()),
+///         (CandleEventKind::Havdalah, "Sat 21:09".to_string()),
+///     ]
+/// );
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn candle_lighting<Tz: TimeZone>(
+    days: HebrewDateRange,
+    location: Location,
+    coordinates: Coordinates,
+    tz: &Tz,
+    options: CandleLightingOptions,
+) -> Result<Vec<CandleEvent<Tz>>, ConversionError> {
+    let Coordinates {
+        latitude,
+        longitude,
+        ..
+    } = coordinates;
+    let sunset_on = |date: HebrewDate| {
+        sunset(date.to_civil_date()?, latitude, longitude).ok_or(ConversionError::NoSunset)
+    };
+    let end_of = |date: HebrewDate| match options.day_end {
+        DayEnd::Degrees(degrees) => time_at_zenith(
+            date.to_civil_date()?,
+            latitude,
+            longitude,
+            90.0 + degrees,
+            false,
+        )
+        .ok_or(ConversionError::NoSunset),
+        DayEnd::Minutes(minutes) => Ok(sunset_on(date)? + Duration::minutes(minutes)),
+    };
+
+    let mut events = Vec::new();
+    for day in days.filter(|x| is_rest_day(*x, location)) {
+        let yom_tov = yom_tov_on(day, location);
+        let day_before = day
+            .checked_add_days(-1)
+            .ok_or(ConversionError::YearTooSmall)?;
+        //Days can always be added.
+        let day_after = day.checked_add_days(1).unwrap();
+        let (kind, time) = if !is_rest_day(day_before, location) || day.weekday() == Day::Shabbos {
+            (
+                CandleEventKind::CandleLighting,
+                sunset_on(day_before)? - Duration::minutes(options.minutes_before_sunset),
+            )
+        } else {
+            (
+                CandleEventKind::CandleLightingAfterTzeis,
+                end_of(day_before)?,
+            )
+        };
+        events.push(CandleEvent {
+            kind,
+            day,
+            yom_tov,
+            time: time.with_timezone(tz),
+        });
+        if !is_rest_day(day_after, location) {
+            events.push(CandleEvent {
+                kind: CandleEventKind::Havdalah,
+                day,
+                yom_tov,
+                time: end_of(day)?.with_timezone(tz),
+            });
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEW_YORK: Coordinates = Coordinates {
+        latitude: 40.7128,
+        longitude: -74.006,
+        elevation: 0.0,
+    };
+
+    fn kinds(
+        start: HebrewDate,
+        end: HebrewDate,
+        location: Location,
+    ) -> Vec<(CandleEventKind, HebrewDate)> {
+        candle_lighting(
+            HebrewDateRange::new(start, end),
+            location,
+            NEW_YORK,
+            &Utc,
+            CandleLightingOptions::default(),
+        )
+        .unwrap()
+        .iter()
+        .map(|x| (x.kind, x.day))
+        .collect()
+    }
+
+    #[test]
+    fn yom_tov_next_to_shabbos() {
+        use CandleEventKind::*;
+        //Pesach 5780 started on Thursday.
+        let start = hebrew_date!(5780, Nissan, 14);
+        let end = hebrew_date!(5780, Nissan, 17);
+        assert_eq!(
+            kinds(start, end, Location::Chul),
+            vec![
+                (CandleLighting, hebrew_date!(5780, Nissan, 15)),
+                (CandleLightingAfterTzeis, hebrew_date!(5780, Nissan, 16)),
+                (CandleLighting, hebrew_date!(5780, Nissan, 17)),
+                (Havdalah, hebrew_date!(5780, Nissan, 17)),
+            ]
+        );
+        assert_eq!(
+            kinds(start, end, Location::Israel),
+            vec![
+                (CandleLighting, hebrew_date!(5780, Nissan, 15)),
+                (Havdalah, hebrew_date!(5780, Nissan, 15)),
+                (CandleLighting, hebrew_date!(5780, Nissan, 17)),
+                (Havdalah, hebrew_date!(5780, Nissan, 17)),
+            ]
+        );
+        //Rosh Hashana 5781 started on Shabbos.
+        for location in Location::all() {
+            assert_eq!(
+                kinds(
+                    hebrew_date!(5781, Tishrei, 1),
+                    hebrew_date!(5781, Tishrei, 3),
+                    location
+                ),
+                vec![
+                    (CandleLighting, hebrew_date!(5781, Tishrei, 1)),
+                    (CandleLightingAfterTzeis, hebrew_date!(5781, Tishrei, 2)),
+                    (Havdalah, hebrew_date!(5781, Tishrei, 2)),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn events_are_in_order() {
+        for location in Location::all() {
+            let year = HebrewYear::new(5780).unwrap();
+            let events = candle_lighting(
+                year.days(),
+                location,
+                NEW_YORK,
+                &Utc,
+                CandleLightingOptions::default(),
+            )
+            .unwrap();
+            for pair in events.windows(2) {
+                assert!(pair[0].time < pair[1].time);
+                //Candle lighting and havdalah alternate, except for Yom Tov right after Shabbos or
+                //Yom Tov.
+                let lit_again = pair[1].kind == CandleEventKind::CandleLightingAfterTzeis
+                    || pair[1].day.weekday() == Day::Shabbos;
+                if pair[0].kind != CandleEventKind::Havdalah && !lit_again {
+                    assert_eq!(pair[1].kind, CandleEventKind::Havdalah);
+                }
+            }
+            let count = |kind| events.iter().filter(|x| x.kind == kind).count();
+            //Every week has a Shabbos.
+            assert!(count(CandleEventKind::Havdalah) >= 50);
+            assert!(
+                count(CandleEventKind::CandleLighting)
+                    + count(CandleEventKind::CandleLightingAfterTzeis)
+                    > count(CandleEventKind::Havdalah)
+            );
+        }
+    }
+
+    #[test]
+    fn options_are_used() {
+        let jerusalem = Coordinates {
+            latitude: 31.778,
+            longitude: 35.2354,
+            elevation: 754.0,
+        };
+        let options = CandleLightingOptions {
+            minutes_before_sunset: 40,
+            day_end: DayEnd::Minutes(42),
+        };
+        //The 6th of Tishrei 5779 was Shabbos.
+        let days = HebrewDateRange::new(
+            hebrew_date!(5779, Tishrei, 6),
+            hebrew_date!(5779, Tishrei, 6),
+        );
+        let events = candle_lighting(days, Location::Israel, jerusalem, &Utc, options).unwrap();
+        let friday = hebrew_date!(5779, Tishrei, 5).to_civil_date().unwrap();
+        let saturday = hebrew_date!(5779, Tishrei, 6).to_civil_date().unwrap();
+        assert_eq!(
+            events[0].time,
+            sunset(friday, 31.778, 35.2354).unwrap() - Duration::minutes(40)
+        );
+        assert_eq!(
+            events[1].time,
+            sunset(saturday, 31.778, 35.2354).unwrap() + Duration::minutes(42)
+        );
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].yom_tov, None);
+    }
+
+    #[test]
+    fn dates_past_chrono_are_errors() {
+        let friday = hebrew_date!(300000, Tishrei, 1)
+            .next_weekday(Day::Friday)
+            .unwrap();
+        let days = HebrewDateRange::new(friday, friday);
+        assert_eq!(
+            candle_lighting(
+                days,
+                Location::Chul,
+                NEW_YORK,
+                &Utc,
+                CandleLightingOptions::default()
+            )
+            .unwrap_err(),
+            ConversionError::YearTooLarge
+        );
+    }
+}
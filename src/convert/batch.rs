@@ -0,0 +1,300 @@
+use alloc::vec::Vec;
+use core::num::NonZeroI8;
+
+use chrono::prelude::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::convert::{days_since_epoch_of, HebrewDate, HebrewYear};
+use crate::prelude::{ConversionError, HebrewMonth};
+
+// The amount of dates each thread converts with its own BatchConverter.
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE: usize = 4096;
+
+/// Converts many dates at once.
+///
+/// Building a HebrewYear is the expensive part of converting a date, so a BatchConverter holds on
+/// to the last HebrewYear it used, and only builds a new one when a date isn't in that year. The
+/// slice functions convert the dates in sorted order, so every year is only built once (or once
+/// per thread), and then return the results in the order the dates were given in.
+///
+/// With the `rayon` feature, `par_convert_gregorian_slice` and `par_convert_hebrew_slice` split the
+/// dates between threads.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+///
+/// use chrono::prelude::*;
+/// use heca_lib::prelude::*;
+/// use heca_lib::{BatchConverter, HebrewDate};
+///
+/// let times = [
+///     Utc.ymd(2018, 9, 10).and_hms(12, 0, 0),
+///     Utc.ymd(2018, 9, 9).and_hms(12, 0, 0),
+///     Utc.ymd(2018, 9, 9).and_hms(19, 0, 0),
+/// ];
+/// let dates = BatchConverter::new().convert_gregorian_slice(&times);
+/// assert_eq!(dates[0], HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()));
+/// assert_eq!(dates[1], HebrewDate::from_ymd(5778, HebrewMonth::Elul, NonZeroI8::new(29).unwrap()));
+/// assert_eq!(dates[2], HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()));
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BatchConverter {
+    year: Option<HebrewYear>,
+}
+
+impl BatchConverter {
+    /// Returns a new BatchConverter.
+    pub fn new() -> BatchConverter {
+        BatchConverter { year: None }
+    }
+
+    /// Returns the HebrewDate of a time, like `HebrewDate::try_from`.
+    ///
+    /// # Error Values:
+    /// * YearTooSmall - The date is before the year 1.
+    pub fn convert_gregorian(
+        &mut self,
+        date: DateTime<Utc>,
+    ) -> Result<HebrewDate, ConversionError> {
+        let days = days_since_epoch_of(date);
+        if let Some(year) = self.year {
+            if days >= year.days_since_epoch && days < year.days_since_epoch + year.year_len as i64
+            {
+                return Ok(year.get_hebrewdate_from_days_after_rh(days));
+            }
+        }
+        let date = HebrewDate::from_days_since_epoch(days)?;
+        self.year = Some(date.year);
+        Ok(date)
+    }
+
+    /// Returns when a Hebrew date starts, like `HebrewDate::from_ymd` followed by converting it to
+    /// a `DateTime<Utc>`.
+    ///
+    /// # Error Values:
+    /// The same as `HebrewDate::from_ymd`, and:
+    /// * YearTooLarge - The date is after the last date chrono supports (in the year 262143).
+    pub fn convert_hebrew(
+        &mut self,
+        year: u64,
+        month: HebrewMonth,
+        day: NonZeroI8,
+    ) -> Result<DateTime<Utc>, ConversionError> {
+        let hebrew_year = match self.year {
+            Some(hebrew_year) if hebrew_year.year == year => hebrew_year,
+            _ => {
+                let hebrew_year = HebrewYear::new(year)?;
+                self.year = Some(hebrew_year);
+                hebrew_year
+            }
+        };
+        hebrew_year.get_hebrew_date(month, day)?.try_to_gregorian()
+    }
+
+    /// Returns the HebrewDate of every time in `dates`, in the same order.
+    pub fn convert_gregorian_slice(
+        &mut self,
+        dates: &[DateTime<Utc>],
+    ) -> Vec<Result<HebrewDate, ConversionError>> {
+        let order = sorted_indices(dates, |date| *date);
+        in_original_order(
+            dates.len(),
+            order
+                .into_iter()
+                .map(|i| (i, self.convert_gregorian(dates[i])))
+                .collect(),
+        )
+    }
+
+    /// Returns when every Hebrew date in `dates` starts, in the same order.
+    pub fn convert_hebrew_slice(
+        &mut self,
+        dates: &[(u64, HebrewMonth, NonZeroI8)],
+    ) -> Vec<Result<DateTime<Utc>, ConversionError>> {
+        let order = sorted_indices(dates, |(year, _, _)| *year);
+        in_original_order(
+            dates.len(),
+            order
+                .into_iter()
+                .map(|i| {
+                    let (year, month, day) = dates[i];
+                    (i, self.convert_hebrew(year, month, day))
+                })
+                .collect(),
+        )
+    }
+
+    /// Like `convert_gregorian_slice`, but splits the dates between threads.
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_gregorian_slice(
+        dates: &[DateTime<Utc>],
+    ) -> Vec<Result<HebrewDate, ConversionError>> {
+        let order = sorted_indices(dates, |date| *date);
+        in_original_order(
+            dates.len(),
+            order
+                .par_chunks(CHUNK_SIZE)
+                .flat_map(|chunk| {
+                    let mut converter = BatchConverter::new();
+                    chunk
+                        .iter()
+                        .map(|i| (*i, converter.convert_gregorian(dates[*i])))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        )
+    }
+
+    /// Like `convert_hebrew_slice`, but splits the dates between threads.
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_hebrew_slice(
+        dates: &[(u64, HebrewMonth, NonZeroI8)],
+    ) -> Vec<Result<DateTime<Utc>, ConversionError>> {
+        let order = sorted_indices(dates, |(year, _, _)| *year);
+        in_original_order(
+            dates.len(),
+            order
+                .par_chunks(CHUNK_SIZE)
+                .flat_map(|chunk| {
+                    let mut converter = BatchConverter::new();
+                    chunk
+                        .iter()
+                        .map(|i| {
+                            let (year, month, day) = dates[*i];
+                            (*i, converter.convert_hebrew(year, month, day))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        )
+    }
+}
+
+//Returns the indices of `dates`, sorted by `key`, so that dates in the same year come one after
+//another.
+fn sorted_indices<T, K: Ord>(dates: &[T], key: impl Fn(&T) -> K) -> Vec<usize> {
+    let mut order = (0..dates.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|i| key(&dates[*i]));
+    order
+}
+
+//Puts every result back in the place of the date it came from.
+fn in_original_order<R>(len: usize, results: Vec<(usize, R)>) -> Vec<R> {
+    let mut ordered = (0..len).map(|_| None).collect::<Vec<_>>();
+    for (i, result) in results {
+        ordered[i] = Some(result);
+    }
+    //Every index was converted exactly once.
+    ordered.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use std::convert::TryFrom;
+
+    fn times() -> Vec<DateTime<Utc>> {
+        //Go back and forth over year boundaries, and include times before the year 1.
+        (-20000..20000)
+            .map(|i| Utc.ymd(2000, 1, 1).and_hms(0, 0, 0) + Duration::hours(i * 13))
+            .chain((0..1000).map(|i| Utc.ymd(2000 - i * 7, 1, 1).and_hms(0, 0, 0)))
+            .chain((0..1000).map(|i| Utc.ymd(-3760, 9, 6).and_hms(0, 0, 0) + Duration::hours(i)))
+            .collect()
+    }
+
+    #[test]
+    fn batch_matches_single_conversion() {
+        let times = times();
+        let expected = times
+            .iter()
+            .map(|x| HebrewDate::try_from(*x))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            BatchConverter::new().convert_gregorian_slice(&times),
+            expected
+        );
+
+        let dates = expected
+            .iter()
+            .filter_map(|x| x.ok())
+            .map(|x| (x.year(), x.month(), x.day()))
+            .chain(vec![
+                (0, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()),
+                (5779, HebrewMonth::Adar, NonZeroI8::new(1).unwrap()),
+                (5779, HebrewMonth::Tishrei, NonZeroI8::new(31).unwrap()),
+            ])
+            .collect::<Vec<_>>();
+        let expected = dates
+            .iter()
+            .map(|(year, month, day)| HebrewDate::from_ymd(*year, *month, *day).map(|x| x.into()))
+            .collect::<Vec<_>>();
+        assert_eq!(BatchConverter::new().convert_hebrew_slice(&dates), expected);
+    }
+
+    #[test]
+    fn unsorted_slices_keep_their_order() {
+        let mut times = times();
+        times.reverse();
+        let expected = times
+            .iter()
+            .map(|x| HebrewDate::try_from(*x))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            BatchConverter::new().convert_gregorian_slice(&times),
+            expected
+        );
+        //Interleave dates from two years, so that they'd need a new year for every date if they
+        //weren't sorted.
+        let dates = (1..=29)
+            .flat_map(|day| {
+                let day = NonZeroI8::new(day).unwrap();
+                vec![
+                    (5780, HebrewMonth::Nissan, day),
+                    (5700, HebrewMonth::Nissan, day),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let expected = dates
+            .iter()
+            .map(|(year, month, day)| HebrewDate::from_ymd(*year, *month, *day).map(|x| x.into()))
+            .collect::<Vec<_>>();
+        assert_eq!(BatchConverter::new().convert_hebrew_slice(&dates), expected);
+    }
+
+    #[test]
+    fn dates_past_chrono_are_errors() {
+        let dates = [
+            (300_000, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()),
+            (5780, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()),
+        ];
+        let results = BatchConverter::new().convert_hebrew_slice(&dates);
+        assert_eq!(results[0], Err(ConversionError::YearTooLarge));
+        assert_eq!(results[1], Ok(Utc.ymd(2019, 9, 29).and_hms(18, 0, 0)));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_batch_matches_batch() {
+        let times = times();
+        let expected = BatchConverter::new().convert_gregorian_slice(&times);
+        assert_eq!(
+            BatchConverter::par_convert_gregorian_slice(&times),
+            expected
+        );
+        let dates = expected
+            .iter()
+            .filter_map(|x| x.ok())
+            .map(|x| (x.year(), x.month(), x.day()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            BatchConverter::par_convert_hebrew_slice(&dates),
+            BatchConverter::new().convert_hebrew_slice(&dates)
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+use core::num::NonZeroI8;
+use core::ops::Range;
+
+use crate::convert::{HebrewDate, HebrewYear};
+use crate::prelude::{ConversionError, HebrewMonth};
+
+/// A table of precomputed HebrewYears.
+///
+/// `HebrewYear::new` (and so `HebrewDate::from_ymd`, converting a Gregorian date and
+/// `get_holidays`) looks up Rosh Hashana of the years 5600 until 6000 in a table which is built at
+/// compile time, and calculates the Molad and the postponements of Rosh Hashana of any other year
+/// every time it's called. A HebrewYearCache builds every HebrewYear in its range once, so that
+/// getting one of those years afterwards is just a lookup, for any range of years. Years outside
+/// the range are built as usual.
+///
+/// Building the table is cheap (a few microseconds for a few hundred years), and it can be shared
+/// between threads. `extend` adds more years to it.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+///
+/// use heca_lib::prelude::*;
+/// use heca_lib::{HebrewDate, HebrewYear, HebrewYearCache};
+///
+/// let cache = HebrewYearCache::new(5700..5900)?;
+/// assert_eq!(cache.get(5779)?, HebrewYear::new(5779)?);
+/// assert_eq!(
+///     cache.get_hebrew_date(5779, HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())?,
+///     HebrewDate::from_ymd(5779, HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())?
+/// );
+/// let holidays = cache
+///     .get(5779)?
+///     .get_holidays(Location::Chul, &[TorahReadingType::YomTov]);
+/// assert_eq!(holidays.len(), 22);
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HebrewYearCache {
+    first_year: u64,
+    years: Vec<HebrewYear>,
+}
+
+impl HebrewYearCache {
+    /// Returns a HebrewYearCache with every year in `years`.
+    ///
+    /// # Error Values:
+    /// * YearTooSmall - The range includes the year 0.
+    pub fn new(years: Range<u64>) -> Result<HebrewYearCache, ConversionError> {
+        Ok(HebrewYearCache {
+            first_year: years.start,
+            years: years.map(HebrewYear::new).collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    /// Adds every year in `years` to the table, along with any years between them and the years
+    /// which are already in it.
+    ///
+    /// # Error Values:
+    /// * YearTooSmall - The range includes the year 0. The table isn't changed.
+    pub fn extend(&mut self, years: Range<u64>) -> Result<(), ConversionError> {
+        let range = self.range();
+        if years.is_empty() {
+            return Ok(());
+        } else if range.is_empty() {
+            *self = HebrewYearCache::new(years)?;
+            return Ok(());
+        }
+        let start = range.start.min(years.start);
+        let end = range.end.max(years.end);
+        let before = (start..range.start)
+            .map(HebrewYear::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        let after = (range.end..end)
+            .map(HebrewYear::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.years.splice(0..0, before);
+        self.years.extend(after);
+        self.first_year = start;
+        Ok(())
+    }
+
+    /// Returns the range of years which are in the table.
+    pub fn range(&self) -> Range<u64> {
+        self.first_year..self.first_year + self.years.len() as u64
+    }
+
+    /// Returns true if the year is in the table.
+    pub fn contains(&self, year: u64) -> bool {
+        self.range().contains(&year)
+    }
+
+    /// Returns the HebrewYear of the given year, from the table if it's there.
+    ///
+    /// # Error Values:
+    /// * YearTooSmall - The year is 0.
+    pub fn get(&self, year: u64) -> Result<HebrewYear, ConversionError> {
+        if self.contains(year) {
+            Ok(self.years[(year - self.first_year) as usize])
+        } else {
+            HebrewYear::new(year)
+        }
+    }
+
+    /// Like `HebrewDate::from_ymd`, but uses the table to get the year.
+    ///
+    /// # Error Values:
+    /// The same as `HebrewDate::from_ymd`.
+    pub fn get_hebrew_date(
+        &self,
+        year: u64,
+        month: HebrewMonth,
+        day: NonZeroI8,
+    ) -> Result<HebrewDate, ConversionError> {
+        self.get(year)?.get_hebrew_date(month, day)
+    }
+}
+
+/// Returns a HebrewYearCache with the years 5600 until 6000 (1839 - 2240).
+impl Default for HebrewYearCache {
+    fn default() -> HebrewYearCache {
+        //These years are all above 0, so this can't fail.
+        HebrewYearCache::new(5600..6000).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_matches_hebrew_year() {
+        let cache = HebrewYearCache::new(1..2000).unwrap();
+        assert_eq!(cache.range(), 1..2000);
+        for year in 1..3000 {
+            let hebrew_year = HebrewYear::new(year).unwrap();
+            let cached = cache.get(year).unwrap();
+            assert_eq!(cached, hebrew_year);
+            assert_eq!(cache.contains(year), year < 2000);
+        }
+        assert_eq!(cache.get(0), Err(ConversionError::YearTooSmall));
+        assert_eq!(
+            HebrewYearCache::new(0..10).unwrap_err(),
+            ConversionError::YearTooSmall
+        );
+        assert_eq!(HebrewYearCache::default().range(), 5600..6000);
+
+        let mut cache = HebrewYearCache::new(100..100).unwrap();
+        cache.extend(200..300).unwrap();
+        assert_eq!(cache.range(), 200..300);
+        cache.extend(50..60).unwrap();
+        assert_eq!(cache.range(), 50..300);
+        cache.extend(250..400).unwrap();
+        assert_eq!(cache.range(), 50..400);
+        assert_eq!(cache.extend(0..10), Err(ConversionError::YearTooSmall));
+        assert_eq!(cache.range(), 50..400);
+        for year in 1..500 {
+            assert_eq!(cache.get(year).unwrap(), HebrewYear::new(year).unwrap());
+        }
+        assert_eq!(
+            cache.get_hebrew_date(1500, HebrewMonth::Adar1, NonZeroI8::new(1).unwrap()),
+            HebrewDate::from_ymd(1500, HebrewMonth::Adar1, NonZeroI8::new(1).unwrap())
+        );
+    }
+}
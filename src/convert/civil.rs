@@ -0,0 +1,168 @@
+use core::convert::TryFrom;
+
+use chrono::prelude::*;
+
+use crate::convert::{naive_date_from_rata_die, HebrewDate};
+use crate::prelude::ConversionError;
+
+impl HebrewDate {
+    /// Returns the HebrewDate of a civil date.
+    ///
+    /// # Arguments
+    ///
+    /// `date` - The civil date.
+    ///
+    /// `after_sunset` - If this is true, returns the Hebrew day which starts on the evening of
+    /// `date`, instead of the one whose daytime falls out on `date`.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The date is before the year 1.
+    /// * `YearTooLarge` - `after_sunset` is true, and `date` is the last date chrono supports.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    ///
+    /// use chrono::NaiveDate;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// assert_eq!(
+    ///     HebrewDate::from_naive_date(NaiveDate::from_ymd(2018, 9, 18), false)?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(9).unwrap())?
+    /// );
+    /// assert_eq!(
+    ///     HebrewDate::from_naive_date(NaiveDate::from_ymd(2018, 9, 18), true)?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn from_naive_date(
+        date: NaiveDate,
+        after_sunset: bool,
+    ) -> Result<HebrewDate, ConversionError> {
+        let date = if after_sunset {
+            date.succ_opt().ok_or(ConversionError::YearTooLarge)?
+        } else {
+            date
+        };
+        HebrewDate::from_civil_date(date)
+    }
+
+    // Returns the Hebrew day whose daytime falls out on the given civil day.
+    pub(crate) fn from_civil_date(date: NaiveDate) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_rata_die(date.num_days_from_ce() as i64)
+    }
+
+    // Returns the civil day on which the daytime of this Hebrew day falls out, or YearTooLarge if
+    // it's after the last day chrono supports.
+    pub(crate) fn to_civil_date(self) -> Result<NaiveDate, ConversionError> {
+        naive_date_from_rata_die(self.to_rata_die())
+    }
+}
+
+/// Returns the HebrewDate whose daytime falls out on a civil date.
+///
+/// To get the Hebrew date which starts on the evening of the civil date, use
+/// `HebrewDate::from_naive_date` with `after_sunset` set to true.
+///
+/// # Error Values:
+/// * YearTooSmall - The date is before the year 1.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+/// use std::convert::TryInto;
+///
+/// use chrono::NaiveDate;
+/// use heca_lib::prelude::*;
+/// use heca_lib::HebrewDate;
+///
+/// let hebrew_date: HebrewDate = NaiveDate::from_ymd(2018, 9, 10).try_into()?;
+/// assert_eq!(hebrew_date, HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())?);
+/// # Ok::<(),ConversionError>(())
+/// ```
+impl TryFrom<NaiveDate> for HebrewDate {
+    type Error = ConversionError;
+    fn try_from(date: NaiveDate) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_civil_date(date)
+    }
+}
+
+/// Returns the civil date on which the daytime of a HebrewDate falls out.
+///
+/// # Error Values:
+/// * YearTooLarge - The date is after the last date a NaiveDate can hold (in the year 262143).
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+/// use std::convert::TryInto;
+///
+/// use chrono::NaiveDate;
+/// use heca_lib::prelude::*;
+/// use heca_lib::HebrewDate;
+///
+/// let yom_kippur: NaiveDate = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?.try_into()?;
+/// assert_eq!(yom_kippur, NaiveDate::from_ymd(2018, 9, 19));
+/// # Ok::<(),ConversionError>(())
+/// ```
+impl TryFrom<HebrewDate> for NaiveDate {
+    type Error = ConversionError;
+    fn try_from(date: HebrewDate) -> Result<NaiveDate, ConversionError> {
+        date.to_civil_date()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use chrono::Duration;
+    use core::convert::TryInto;
+    use core::num::NonZeroI8;
+
+    #[test]
+    fn naive_date_matches_utc_conversion() {
+        let mut date = NaiveDate::from_ymd(5, 1, 1);
+        while date < NaiveDate::from_ymd(3000, 1, 1) {
+            let hebrew_date = HebrewDate::try_from(date).unwrap();
+            let from_utc: HebrewDate = Utc
+                .from_utc_datetime(&date.and_hms(12, 0, 0))
+                .try_into()
+                .unwrap();
+            assert_eq!(hebrew_date, from_utc);
+            assert_eq!(NaiveDate::try_from(hebrew_date), Ok(date));
+            assert_eq!(
+                HebrewDate::from_naive_date(date, true).unwrap(),
+                hebrew_date.checked_add_days(1).unwrap()
+            );
+            date += Duration::days(17);
+        }
+        assert_eq!(
+            HebrewDate::try_from(NaiveDate::from_ymd(-3761, 1, 1)),
+            Err(ConversionError::YearTooSmall)
+        );
+        let last_day = NaiveDate::from_ymd(262_143, 12, 31);
+        let hebrew_date = HebrewDate::try_from(last_day).unwrap();
+        assert_eq!(NaiveDate::try_from(hebrew_date), Ok(last_day));
+        assert_eq!(
+            HebrewDate::from_naive_date(last_day, true),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(
+            NaiveDate::try_from(hebrew_date.checked_add_days(1).unwrap()),
+            Err(ConversionError::YearTooLarge)
+        );
+        let far_future =
+            HebrewDate::from_ymd(300_000, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+                .unwrap();
+        assert_eq!(
+            NaiveDate::try_from(far_future),
+            Err(ConversionError::YearTooLarge)
+        );
+    }
+}
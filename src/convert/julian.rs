@@ -0,0 +1,450 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::prelude::*;
+
+#[cfg(feature = "chrono")]
+use crate::convert::naive_date_from_rata_die;
+use crate::convert::HebrewDate;
+use crate::prelude::ConversionError;
+
+// The Rata Die of the 1st of March, year 0 of the Julian calendar.
+const MARCH_FIRST_OF_YEAR_0: i64 = -307;
+
+/// A date in the Julian calendar.
+///
+/// Years are counted like chrono counts them, so the year 1 BCE is year 0, 2 BCE is year -1, etc.
+/// The year starts on the 1st of January.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+///
+/// use heca_lib::prelude::*;
+/// use heca_lib::{HebrewDate, JulianDate};
+///
+/// // The Gregorian reform - the day after the 4th of October 1582 (Julian) was the 15th (Gregorian).
+/// let last_julian_day = JulianDate::from_ymd(1582, 10, 4).unwrap();
+/// let hebrew_date = HebrewDate::from_julian(last_julian_day, false)?;
+/// assert_eq!(hebrew_date, HebrewDate::from_ymd(5343, HebrewMonth::Tishrei, NonZeroI8::new(18).unwrap())?);
+/// assert_eq!(hebrew_date.to_julian()?, last_julian_day);
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JulianDate {
+    /// Returns a JulianDate, or None if the month or day is invalid.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<JulianDate> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(JulianDate { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, starting from 1 for January.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    //The Rata Die of this day, which is the same as chrono's num_days_from_ce.
+    fn to_rata_die(self) -> i64 {
+        //Count the year from March, so that the leap day is at the end of the year.
+        let (year, month) = if self.month < 3 {
+            (self.year as i64 - 1, self.month as i64 + 9)
+        } else {
+            (self.year as i64, self.month as i64 - 3)
+        };
+        MARCH_FIRST_OF_YEAR_0
+            + 365 * year
+            + year.div_euclid(4)
+            + (153 * month + 2) / 5
+            + self.day as i64
+            - 1
+    }
+
+    //The Julian date of a Rata Die, or an error if its year doesn't fit in an i32.
+    fn from_rata_die(days: i64) -> Result<JulianDate, ConversionError> {
+        let days = days - MARCH_FIRST_OF_YEAR_0;
+        let year = (4 * days + 3).div_euclid(1461);
+        let day_of_year = days - 365 * year - year.div_euclid(4);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        //Count the year from January again.
+        let (year, month) = if month < 10 {
+            (year, month + 3)
+        } else {
+            (year + 1, month - 9)
+        };
+        let year = i32::try_from(year).map_err(|_| {
+            if year < 0 {
+                ConversionError::YearTooSmall
+            } else {
+                ConversionError::YearTooLarge
+            }
+        })?;
+        Ok(JulianDate {
+            year,
+            month: month as u32,
+            day: day as u32,
+        })
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year.rem_euclid(4) == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns the Julian date of the same day as a (proleptic) Gregorian date.
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for JulianDate {
+    fn from(date: NaiveDate) -> JulianDate {
+        //Every year a NaiveDate can hold fits in an i32, so this can't fail.
+        JulianDate::from_rata_die(date.num_days_from_ce() as i64).unwrap()
+    }
+}
+
+/// Returns the (proleptic) Gregorian date of the same day as a Julian date.
+///
+/// # Error Values:
+/// * YearTooSmall - The date is before the first date a NaiveDate can hold (in the year -262144).
+/// * YearTooLarge - The date is after the last date a NaiveDate can hold (in the year 262143).
+#[cfg(feature = "chrono")]
+impl TryFrom<JulianDate> for NaiveDate {
+    type Error = ConversionError;
+    fn try_from(date: JulianDate) -> Result<NaiveDate, ConversionError> {
+        naive_date_from_rata_die(date.to_rata_die())
+    }
+}
+
+/// A civil date in the calendar which was in use at the time - the Julian calendar before the
+/// Gregorian reform, and the Gregorian calendar from then on.
+///
+/// The reform was adopted at different times in different places, so you need to choose when it
+/// happened. In Catholic countries, the first Gregorian day was the 15th of October 1582, and in
+/// Britain and its colonies it was the 14th of September 1752.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HistoricalDate {
+    Julian(JulianDate),
+    Gregorian(NaiveDate),
+}
+
+#[cfg(feature = "chrono")]
+impl HistoricalDate {
+    /// Returns the HistoricalDate of a (proleptic) Gregorian date.
+    ///
+    /// # Arguments
+    ///
+    /// `date` - The date to convert.
+    ///
+    /// `reform` - The first day of the Gregorian calendar.
+    pub fn new(date: NaiveDate, reform: NaiveDate) -> HistoricalDate {
+        if date < reform {
+            HistoricalDate::Julian(date.into())
+        } else {
+            HistoricalDate::Gregorian(date)
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl fmt::Display for HistoricalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HistoricalDate::Julian(date) => write!(f, "{} (Julian)", date),
+            HistoricalDate::Gregorian(date) => write!(f, "{} (Gregorian)", date),
+        }
+    }
+}
+
+/// Returns the (proleptic) Gregorian date of a HistoricalDate.
+///
+/// # Error Values:
+/// * YearTooSmall - The date is before the first date a NaiveDate can hold (in the year -262144).
+/// * YearTooLarge - The date is after the last date a NaiveDate can hold (in the year 262143).
+#[cfg(feature = "chrono")]
+impl TryFrom<HistoricalDate> for NaiveDate {
+    type Error = ConversionError;
+    fn try_from(date: HistoricalDate) -> Result<NaiveDate, ConversionError> {
+        match date {
+            HistoricalDate::Julian(date) => NaiveDate::try_from(date),
+            HistoricalDate::Gregorian(date) => Ok(date),
+        }
+    }
+}
+
+impl HebrewDate {
+    /// Returns the Julian date on which the daytime of this HebrewDate falls out.
+    ///
+    /// # Error Values
+    /// * `YearTooLarge` - The Julian year doesn't fit in an i32.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    ///
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::{HebrewDate, JulianDate};
+    ///
+    /// // The Rambam passed away on the 20th of Teves, 4965.
+    /// let date = HebrewDate::from_ymd(4965, HebrewMonth::Teves, NonZeroI8::new(20).unwrap())?;
+    /// assert_eq!(date.to_julian()?, JulianDate::from_ymd(1204, 12, 13).unwrap());
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn to_julian(self) -> Result<JulianDate, ConversionError> {
+        JulianDate::from_rata_die(self.to_rata_die())
+    }
+
+    /// Returns the HebrewDate of a Julian date.
+    ///
+    /// # Arguments
+    ///
+    /// `date` - The Julian date.
+    ///
+    /// `after_sunset` - If this is true, returns the Hebrew day which starts on the evening of
+    /// `date`, instead of the one whose daytime falls out on `date`.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The date is before the year 1.
+    pub fn from_julian(
+        date: JulianDate,
+        after_sunset: bool,
+    ) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_rata_die(date.to_rata_die() + if after_sunset { 1 } else { 0 })
+    }
+
+    /// Returns the date on which the daytime of this HebrewDate falls out, in the Julian calendar
+    /// if it's before `reform`, and in the Gregorian calendar otherwise.
+    ///
+    /// # Error Values
+    /// * `YearTooLarge` - The date is after the last date chrono supports (in the year 262143).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    ///
+    /// use chrono::NaiveDate;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::{HebrewDate, HistoricalDate, JulianDate};
+    ///
+    /// let reform = NaiveDate::from_ymd(1582, 10, 15);
+    /// let date = HebrewDate::from_ymd(5343, HebrewMonth::Tishrei, NonZeroI8::new(18).unwrap())?;
+    /// assert_eq!(
+    ///     date.to_historical(reform)?,
+    ///     HistoricalDate::Julian(JulianDate::from_ymd(1582, 10, 4).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     (date + chrono::Duration::days(1)).to_historical(reform)?,
+    ///     HistoricalDate::Gregorian(NaiveDate::from_ymd(1582, 10, 15))
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn to_historical(self, reform: NaiveDate) -> Result<HistoricalDate, ConversionError> {
+        Ok(HistoricalDate::new(
+            naive_date_from_rata_die(self.to_rata_die())?,
+            reform,
+        ))
+    }
+}
+
+/// Returns the HebrewDate whose daytime falls out on a Julian date.
+///
+/// # Error Values:
+/// * YearTooSmall - The date is before the year 1.
+impl TryFrom<JulianDate> for HebrewDate {
+    type Error = ConversionError;
+    fn try_from(date: JulianDate) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_julian(date, false)
+    }
+}
+
+/// Returns the HebrewDate whose daytime falls out on a HistoricalDate.
+///
+/// # Error Values:
+/// * YearTooSmall - The date is before the year 1.
+#[cfg(feature = "chrono")]
+impl TryFrom<HistoricalDate> for HebrewDate {
+    type Error = ConversionError;
+    fn try_from(date: HistoricalDate) -> Result<HebrewDate, ConversionError> {
+        let rata_die = match date {
+            HistoricalDate::Julian(date) => date.to_rata_die(),
+            HistoricalDate::Gregorian(date) => date.num_days_from_ce() as i64,
+        };
+        HebrewDate::from_rata_die(rata_die)
+    }
+}
+
+/// Returns the Julian date on which the daytime of a HebrewDate falls out.
+///
+/// # Error Values:
+/// * YearTooLarge - The Julian year doesn't fit in an i32.
+impl TryFrom<HebrewDate> for JulianDate {
+    type Error = ConversionError;
+    fn try_from(date: HebrewDate) -> Result<JulianDate, ConversionError> {
+        date.to_julian()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "chrono")]
+    use crate::prelude::*;
+    #[cfg(feature = "chrono")]
+    use chrono::Duration;
+    #[cfg(feature = "chrono")]
+    use std::num::NonZeroI8;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn julian_matches_known_dates() {
+        let pairs = [
+            ((1582, 10, 4), (1582, 10, 14)),
+            ((1, 1, 3), (1, 1, 1)),
+            ((1900, 2, 29), (1900, 3, 13)),
+            ((2000, 1, 1), (2000, 1, 14)),
+            ((-3760, 10, 7), (-3760, 9, 7)),
+            ((0, 12, 30), (0, 12, 28)),
+        ];
+        for ((jy, jm, jd), (gy, gm, gd)) in pairs.iter() {
+            let julian = JulianDate::from_ymd(*jy, *jm, *jd).unwrap();
+            let gregorian = NaiveDate::from_ymd(*gy, *gm, *gd);
+            assert_eq!(NaiveDate::try_from(julian), Ok(gregorian));
+            assert_eq!(JulianDate::from(gregorian), julian);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn julian_round_trip() {
+        let mut date = NaiveDate::from_ymd(-4000, 1, 1);
+        let mut prev = JulianDate::from(date - Duration::days(1));
+        while date < NaiveDate::from_ymd(2500, 1, 1) {
+            let julian = JulianDate::from(date);
+            assert_eq!(
+                JulianDate::from_ymd(julian.year(), julian.month(), julian.day()),
+                Some(julian)
+            );
+            assert_eq!(NaiveDate::try_from(julian), Ok(date));
+            assert!(prev < julian);
+            prev = julian;
+            date += Duration::days(1);
+        }
+    }
+
+    #[test]
+    fn invalid_julian_dates() {
+        assert!(JulianDate::from_ymd(1900, 2, 29).is_some());
+        assert_eq!(JulianDate::from_ymd(1901, 2, 29), None);
+        assert_eq!(JulianDate::from_ymd(-1, 2, 29), None);
+        assert_eq!(JulianDate::from_ymd(2020, 4, 31), None);
+        assert_eq!(JulianDate::from_ymd(2020, 13, 1), None);
+        assert_eq!(JulianDate::from_ymd(2020, 1, 0), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn dates_past_chrono_are_errors() {
+        let julian = JulianDate::from_ymd(i32::MAX, 1, 1).unwrap();
+        assert_eq!(
+            NaiveDate::try_from(julian),
+            Err(ConversionError::YearTooLarge)
+        );
+        let julian = JulianDate::from_ymd(i32::MIN, 1, 1).unwrap();
+        assert_eq!(
+            NaiveDate::try_from(HistoricalDate::Julian(julian)),
+            Err(ConversionError::YearTooSmall)
+        );
+        let far_future =
+            HebrewDate::from_ymd(300_000, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+                .unwrap();
+        assert_eq!(
+            far_future.to_historical(NaiveDate::from_ymd(1582, 10, 15)),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(
+            HebrewDate::try_from(HistoricalDate::Julian(far_future.to_julian().unwrap())),
+            Ok(far_future)
+        );
+    }
+
+    #[test]
+    fn julian_years_past_i32_are_errors() {
+        let last_day = JulianDate::from_ymd(i32::MAX, 12, 31).unwrap();
+        let hebrew_date = HebrewDate::try_from(last_day).unwrap();
+        assert_eq!(hebrew_date.to_julian(), Ok(last_day));
+        assert_eq!(
+            JulianDate::try_from(hebrew_date.checked_add_days(1).unwrap()),
+            Err(ConversionError::YearTooLarge)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn historical_dates() {
+        let british_reform = NaiveDate::from_ymd(1752, 9, 14);
+        let first_day =
+            HebrewDate::from_ymd(1, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        let julian = JulianDate::from_ymd(-3760, 10, 7).unwrap();
+        assert_eq!(
+            first_day.to_historical(british_reform).unwrap(),
+            HistoricalDate::Julian(julian)
+        );
+        assert_eq!(HebrewDate::try_from(julian), Ok(first_day));
+        assert_eq!(
+            HebrewDate::from_julian(JulianDate::from_ymd(-3760, 10, 6).unwrap(), true),
+            Ok(first_day)
+        );
+        assert_eq!(
+            HebrewDate::try_from(JulianDate::from_ymd(-3760, 10, 6).unwrap()),
+            Err(ConversionError::YearTooSmall)
+        );
+        let date = HebrewDate::try_from(NaiveDate::from_ymd(1752, 9, 14)).unwrap();
+        let historical = date.to_historical(british_reform).unwrap();
+        assert_eq!(historical, HistoricalDate::Gregorian(british_reform));
+        assert_eq!(HebrewDate::try_from(historical), Ok(date));
+        let historical = (date - Duration::days(1))
+            .to_historical(british_reform)
+            .unwrap();
+        assert_eq!(
+            historical,
+            HistoricalDate::Julian(JulianDate::from_ymd(1752, 9, 2).unwrap())
+        );
+        assert_eq!(historical.to_string(), "1752-09-02 (Julian)");
+        assert_eq!(
+            HebrewDate::try_from(historical),
+            Ok(date - Duration::days(1))
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use chrono::prelude::*;
+use chrono::Duration;
+
+use crate::convert::sun::sunset;
+use crate::convert::HebrewDate;
+use crate::prelude::{ConversionError, DayBoundary};
+
+impl HebrewDate {
+    /// Returns the HebrewDate of a time in any time zone, where the day starts according to the
+    /// given DayBoundary.
+    ///
+    /// # Arguments
+    ///
+    /// `date` - The time to convert.
+    ///
+    /// `boundary` - When the Hebrew day starts. For `DayBoundary::Time` and `DayBoundary::Midnight`,
+    /// this is in the time zone of `date`.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The date is before the year 1.
+    /// * `NoSunset` - `boundary` is `DayBoundary::Sunset`, and the sun doesn't set on that day at
+    ///   that location.
+    /// * `YearTooLarge` - The day is after the last date chrono supports.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    ///
+    /// use chrono::prelude::*;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let jerusalem = DayBoundary::Sunset { latitude: 31.778, longitude: 35.2354 };
+    /// let israel_time = FixedOffset::east(3 * 3600);
+    /// // Sunset in Jerusalem on the 9th of September, 2018 was at 6:53 PM.
+    /// assert_eq!(
+    ///     HebrewDate::from_datetime(&israel_time.ymd(2018, 9, 9).and_hms(18, 50, 0), jerusalem)?,
+    ///     HebrewDate::from_ymd(5778, HebrewMonth::Elul, NonZeroI8::new(29).unwrap())?
+    /// );
+    /// assert_eq!(
+    ///     HebrewDate::from_datetime(&israel_time.ymd(2018, 9, 9).and_hms(19, 5, 0), jerusalem)?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())?
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn from_datetime<Tz: TimeZone>(
+        date: &DateTime<Tz>,
+        boundary: DayBoundary,
+    ) -> Result<HebrewDate, ConversionError> {
+        let (civil_date, after_boundary) = match boundary {
+            DayBoundary::Midnight => (date.naive_local().date(), false),
+            DayBoundary::Time(time) => {
+                let local = date.naive_local();
+                (local.date(), local.time() >= time)
+            }
+            DayBoundary::Sunset {
+                latitude,
+                longitude,
+            } => {
+                //Use the local mean time of the location rather than the time zone of the date, so
+                //that the result doesn't depend on which time zone the date was given in.
+                let date = date.with_timezone(&Utc);
+                let civil_date =
+                    (date.naive_utc() + Duration::seconds((longitude * 240.0) as i64)).date();
+                let sunset =
+                    sunset(civil_date, latitude, longitude).ok_or(ConversionError::NoSunset)?;
+                (civil_date, date >= sunset)
+            }
+        };
+        let civil_date = if after_boundary {
+            civil_date.succ_opt().ok_or(ConversionError::YearTooLarge)?
+        } else {
+            civil_date
+        };
+        HebrewDate::from_civil_date(civil_date)
+    }
+
+    /// Returns when this Hebrew day starts in the given time zone, according to the given
+    /// DayBoundary.
+    ///
+    /// # Error Values
+    /// * `NoSunset` - `boundary` is `DayBoundary::Sunset`, and the sun doesn't set on the evening
+    ///   before at that location.
+    /// * `YearTooLarge` - The day is after the last date chrono supports.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    ///
+    /// use chrono::prelude::*;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let new_york_time = FixedOffset::west(4 * 3600);
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// let boundary = DayBoundary::Time(NaiveTime::from_hms(18, 0, 0));
+    /// assert_eq!(
+    ///     yom_kippur.start_in(&new_york_time, boundary)?,
+    ///     new_york_time.ymd(2018, 9, 18).and_hms(18, 0, 0)
+    /// );
+    /// assert_eq!(
+    ///     yom_kippur.end_in(&new_york_time, boundary)?,
+    ///     new_york_time.ymd(2018, 9, 19).and_hms(18, 0, 0)
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn start_in<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        boundary: DayBoundary,
+    ) -> Result<DateTime<Tz>, ConversionError> {
+        start_of_civil_day(self.to_civil_date()?, tz, boundary)
+    }
+
+    /// Returns when this Hebrew day ends (and the next one starts) in the given time zone,
+    /// according to the given DayBoundary.
+    ///
+    /// # Error Values
+    /// * `NoSunset` - `boundary` is `DayBoundary::Sunset`, and the sun doesn't set on that day at
+    ///   that location.
+    /// * `YearTooLarge` - The day (or the one after it) is after the last date chrono supports.
+    pub fn end_in<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        boundary: DayBoundary,
+    ) -> Result<DateTime<Tz>, ConversionError> {
+        let next_day = self
+            .to_civil_date()?
+            .succ_opt()
+            .ok_or(ConversionError::YearTooLarge)?;
+        start_of_civil_day(next_day, tz, boundary)
+    }
+}
+
+// Returns when the Hebrew day whose daytime falls out on the given civil day starts.
+fn start_of_civil_day<Tz: TimeZone>(
+    date: NaiveDate,
+    tz: &Tz,
+    boundary: DayBoundary,
+) -> Result<DateTime<Tz>, ConversionError> {
+    let evening_before = date - Duration::days(1);
+    match boundary {
+        DayBoundary::Midnight => Ok(from_local(tz, date.and_hms(0, 0, 0))),
+        DayBoundary::Time(time) => Ok(from_local(tz, evening_before.and_time(time))),
+        DayBoundary::Sunset {
+            latitude,
+            longitude,
+        } => sunset(evening_before, latitude, longitude)
+            .map(|x| x.with_timezone(tz))
+            .ok_or(ConversionError::NoSunset),
+    }
+}
+
+// Returns the first instant with the given local time. If the local time was skipped (such as when
+// moving to daylight saving time), returns the instant an hour later.
+fn from_local<Tz: TimeZone>(tz: &Tz, time: NaiveDateTime) -> DateTime<Tz> {
+    tz.from_local_datetime(&time)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(time + Duration::hours(1)))
+                .earliest()
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::convert::TryInto;
+    use std::num::NonZeroI8;
+
+    #[test]
+    fn fixed_time_matches_utc_conversion() {
+        let boundary = DayBoundary::Time(NaiveTime::from_hms(18, 0, 0));
+        let mut date = Utc.ymd(1900, 1, 1).and_hms(11, 0, 0);
+        for _ in 0..2000 {
+            let hebrew_date: HebrewDate = date.try_into().unwrap();
+            assert_eq!(
+                HebrewDate::from_datetime(&date, boundary).unwrap(),
+                hebrew_date
+            );
+            assert!(hebrew_date.start_in(&Utc, boundary).unwrap() <= date);
+            assert!(hebrew_date.end_in(&Utc, boundary).unwrap() > date);
+            date = date + Duration::hours(23);
+        }
+    }
+
+    #[test]
+    fn midnight_matches_civil_day() {
+        let tz = FixedOffset::east(2 * 3600);
+        let yom_kippur =
+            HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap()).unwrap();
+        for hour in 0..24 {
+            assert_eq!(
+                HebrewDate::from_datetime(
+                    &tz.ymd(2018, 9, 19).and_hms(hour, 0, 0),
+                    DayBoundary::Midnight
+                )
+                .unwrap(),
+                yom_kippur
+            );
+        }
+        assert_eq!(
+            yom_kippur.start_in(&tz, DayBoundary::Midnight).unwrap(),
+            tz.ymd(2018, 9, 19).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            yom_kippur.end_in(&tz, DayBoundary::Midnight).unwrap(),
+            tz.ymd(2018, 9, 20).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn sunset_boundary() {
+        let tz = FixedOffset::west(4 * 3600);
+        let new_york = DayBoundary::Sunset {
+            latitude: 40.7128,
+            longitude: -74.006,
+        };
+        let shavuos =
+            HebrewDate::from_ymd(5779, HebrewMonth::Sivan, NonZeroI8::new(6).unwrap()).unwrap();
+        // Sunset on June 8th, 2019 was at 8:25 PM.
+        let start = shavuos.start_in(&tz, new_york).unwrap();
+        assert_eq!(start.date(), tz.ymd(2019, 6, 8));
+        assert_eq!(start.hour(), 20);
+        assert_eq!(
+            HebrewDate::from_datetime(&start, new_york).unwrap(),
+            shavuos
+        );
+        assert_eq!(
+            HebrewDate::from_datetime(&(start - Duration::seconds(1)), new_york).unwrap(),
+            shavuos.checked_add_days(-1).unwrap()
+        );
+        // The time zone of the date doesn't matter.
+        assert_eq!(
+            HebrewDate::from_datetime(&Utc.ymd(2019, 6, 9).and_hms(0, 10, 0), new_york).unwrap(),
+            shavuos.checked_add_days(-1).unwrap()
+        );
+        assert_eq!(
+            HebrewDate::from_datetime(&Utc.ymd(2019, 6, 9).and_hms(0, 40, 0), new_york).unwrap(),
+            shavuos
+        );
+    }
+
+    #[test]
+    fn last_day_of_chrono_is_an_error() {
+        let boundary = DayBoundary::Time(NaiveTime::from_hms(18, 0, 0));
+        let last_day = chrono::naive::MAX_DATE;
+        let evening = DateTime::<Utc>::from_utc(last_day.and_hms(19, 0, 0), Utc);
+        assert_eq!(
+            HebrewDate::from_datetime(&evening, boundary),
+            Err(ConversionError::YearTooLarge)
+        );
+        let hebrew_date = HebrewDate::from_civil_date(last_day).unwrap();
+        assert!(hebrew_date.start_in(&Utc, boundary).is_ok());
+        assert_eq!(
+            hebrew_date.end_in(&Utc, boundary),
+            Err(ConversionError::YearTooLarge)
+        );
+    }
+}
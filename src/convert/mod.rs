@@ -1,31 +1,91 @@
-use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 
+pub(crate) use crate::convert::year::backend::day_of_week;
+#[cfg(feature = "chrono")]
+pub(crate) use crate::convert::year::backend::EPOCH_TIMESTAMP;
+use crate::convert::year::backend::{
+    day_of_last_rh, months_before_year, year_of_month, EPOCH_RATA_DIE, JDN_OF_RATA_DIE_0,
+    LAST_YEAR, LAST_YEAR_END, YEAR_1_RH,
+};
 use crate::prelude::*;
-use serde::ser::SerializeStruct;
-use serde::Serialize;
-use std::num::NonZeroI8;
+use core::convert::TryFrom;
+use core::num::NonZeroI8;
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "chrono")]
+mod batch;
+mod cache;
+#[cfg(feature = "chrono")]
+mod civil;
+mod julian;
+#[cfg(all(feature = "std", feature = "chrono"))]
+mod local;
+mod range;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub(crate) mod sun;
 mod year;
+#[cfg(feature = "chrono")]
+#[doc(inline)]
+pub use batch::*;
+#[doc(inline)]
+pub use cache::*;
+#[doc(inline)]
+pub use julian::*;
+#[doc(inline)]
+pub use range::*;
 #[doc(inline)]
 pub use year::*;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "HebrewDateFields", into = "HebrewDateFields")]
 /// HebrewDate holds a specific Hebrew Date. It can be constructed individually or through HebrewYear.
+///
+/// It's serialized as its day, month and year. Deserializing it fails if the date doesn't exist
+/// (such as Adar 1 in a year which isn't a leap year). To serialize it as a string or as a number
+/// instead, see the [serialization](../heca_lib/serialization/index.html) module.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+/// use heca_lib::{hebrew_date, HebrewDate};
+///
+/// let json = serde_json::to_string(&hebrew_date!(5779, Adar1, 14)).unwrap();
+/// assert_eq!(json, r#"{"day":14,"month":"Adar1","year":5779}"#);
+/// assert_eq!(serde_json::from_str::<HebrewDate>(&json).unwrap(), hebrew_date!(5779, Adar1, 14));
+/// assert!(serde_json::from_str::<HebrewDate>(r#"{"day":14,"month":"Adar1","year":5780}"#).is_err());
+/// ```
 pub struct HebrewDate {
     day: NonZeroI8,
     month: HebrewMonth,
     year: HebrewYear,
 }
-impl Serialize for HebrewDate {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        let mut state = serializer.serialize_struct("HebrewDate", 3)?;
-        state.serialize_field("day", &self.day.get())?;
-        state.serialize_field("month", &self.month)?;
-        state.serialize_field("year", &self.year())?;
-        state.end()
+
+//The serialized form of a HebrewDate.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HebrewDate")]
+struct HebrewDateFields {
+    day: i8,
+    month: HebrewMonth,
+    year: u64,
+}
+
+impl From<HebrewDate> for HebrewDateFields {
+    fn from(date: HebrewDate) -> HebrewDateFields {
+        HebrewDateFields {
+            day: date.day.get(),
+            month: date.month,
+            year: date.year(),
+        }
+    }
+}
+
+impl TryFrom<HebrewDateFields> for HebrewDate {
+    type Error = ConversionError;
+    fn try_from(fields: HebrewDateFields) -> Result<HebrewDate, ConversionError> {
+        let day = NonZeroI8::new(fields.day).ok_or(ConversionError::ParseError)?;
+        HebrewDate::from_ymd(fields.year, fields.month, day)
     }
 }
 impl Eq for HebrewDate {}
@@ -35,7 +95,9 @@ impl PartialEq for HebrewDate {
     }
 }
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
+#[cfg(feature = "chrono")]
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 impl Ord for HebrewDate {
     fn cmp(&self, other: &HebrewDate) -> Ordering {
         if self.year() < other.year() {
@@ -62,6 +124,77 @@ impl PartialOrd for HebrewDate {
     }
 }
 
+/// Adds the whole days of a Duration to a HebrewDate.
+///
+/// # Panics
+///
+/// Panics if the result is before the year 1. Use `checked_add_days` to avoid this.
+#[cfg(feature = "chrono")]
+impl Add<Duration> for HebrewDate {
+    type Output = HebrewDate;
+    fn add(self, rhs: Duration) -> HebrewDate {
+        self.checked_add_days(rhs.num_days())
+            .expect("`HebrewDate + Duration` is out of range")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AddAssign<Duration> for HebrewDate {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracts the whole days of a Duration from a HebrewDate.
+///
+/// # Panics
+///
+/// Panics if the result is before the year 1. Use `checked_add_days` to avoid this.
+#[cfg(feature = "chrono")]
+impl Sub<Duration> for HebrewDate {
+    type Output = HebrewDate;
+    fn sub(self, rhs: Duration) -> HebrewDate {
+        self.checked_add_days(-rhs.num_days())
+            .expect("`HebrewDate - Duration` is out of range")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SubAssign<Duration> for HebrewDate {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// Returns the amount of days between two HebrewDates.
+#[cfg(feature = "chrono")]
+impl Sub<HebrewDate> for HebrewDate {
+    type Output = Duration;
+    fn sub(self, rhs: HebrewDate) -> Duration {
+        Duration::days(self.days_since_epoch() - rhs.days_since_epoch())
+    }
+}
+
+//The amount of days between the epoch and the Hebrew day of the given time.
+#[cfg(feature = "chrono")]
+pub(crate) fn days_since_epoch_of(date: DateTime<Utc>) -> i64 {
+    //Round down, so that times before the epoch end up on the right day.
+    (date.timestamp() - EPOCH_TIMESTAMP).div_euclid(24 * 60 * 60)
+}
+
+//The NaiveDate of a Rata Die, or an error if it's outside of the years chrono supports.
+#[cfg(feature = "chrono")]
+pub(crate) fn naive_date_from_rata_die(rata_die: i64) -> Result<NaiveDate, ConversionError> {
+    i32::try_from(rata_die)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or(if rata_die < 0 {
+            ConversionError::YearTooSmall
+        } else {
+            ConversionError::YearTooLarge
+        })
+}
+
 impl HebrewDate {
     /// Returns a HebrewDate on success or a ConversionError on failure.
     ///
@@ -71,27 +204,34 @@ impl HebrewDate {
     /// * `day` - The Hebrew day of month.
     ///
     /// # Error Values
-    /// * `YearTooSmall` - This algorithm won't work if the year is before 3764.
+    /// * `YearTooSmall` - The year is 0.
+    /// * `YearTooLarge` - The year is too large for its Molad to be calculated.
     /// * `IsLeapYear` - I treat Adar, Adar 1 and Adar 2 as three seperate months, so if you want to
-    /// convert a day in Adar 1 or Adar 2 of a leap year, specify which one.
-    ///  * `IsNotLeapYear` - I treat Adar, Adar 1 and Adar 2 as three seperate months, so it won't
-    ///  make sense to get the English date of the first of Adar 1 or Adar 2 if the year isn't a
-    ///  leap year.
-    ///  * `TooManyDaysInMonth` - There are either 29 or 30 days in a month, so it doesn't make sense
-    ///  to find the 50th day of Nissan.
+    ///   convert a day in Adar 1 or Adar 2 of a leap year, specify which one.
+    /// * `IsNotLeapYear` - I treat Adar, Adar 1 and Adar 2 as three seperate months, so it won't
+    ///   make sense to get the English date of the first of Adar 1 or Adar 2 if the year isn't a
+    ///   leap year.
+    /// * `TooManyDaysInMonth` - There are either 29 or 30 days in a month, so it doesn't make sense
+    ///   to find the 50th day of Nissan.
     ///
     /// # Notes:
     ///
     /// Day must be above zero. If it's below zero, the function returns TooManyDaysInMonth. In a future release, day will be a NonZeroU8 so that it will be impossible to supply a negative number.
-    pub fn from_ymd(
+    ///
+    /// This is a `const fn`, so the date can be built at compile time. To have invalid dates fail
+    /// compilation, use the [hebrew_date!](../heca_lib/macro.hebrew_date.html) macro.
+    pub const fn from_ymd(
         year: u64,
         month: HebrewMonth,
         day: NonZeroI8,
     ) -> Result<HebrewDate, ConversionError> {
-        HebrewYear::new(year)?.get_hebrew_date(month, day)
+        match HebrewYear::new(year) {
+            Ok(hebrew_year) => hebrew_year.get_hebrew_date(month, day),
+            Err(e) => Err(e),
+        }
     }
 
-    pub(crate) fn from_ymd_internal(
+    pub(crate) const fn from_ymd_internal(
         month: HebrewMonth,
         day: NonZeroI8, //TODO: Make NonZeroU8
         hebrew_year: HebrewYear,
@@ -103,13 +243,11 @@ impl HebrewDate {
         }
         //Get a HebrewDate object from the Hebrew Year, Month, and Day. Can fail if the year is too
         //small or the day is less than one.
-        if !hebrew_year.is_leap_year()
-            && (month == HebrewMonth::Adar1 || month == HebrewMonth::Adar2)
-        {
+        if !hebrew_year.is_leap_year() && matches!(month, HebrewMonth::Adar1 | HebrewMonth::Adar2) {
             return Err(ConversionError::IsNotLeapYear);
         }
 
-        if hebrew_year.is_leap_year() && month == HebrewMonth::Adar {
+        if hebrew_year.is_leap_year() && matches!(month, HebrewMonth::Adar) {
             return Err(ConversionError::IsLeapYear);
         }
 
@@ -126,60 +264,479 @@ impl HebrewDate {
         })
     }
 
+    #[cfg(feature = "chrono")]
     fn from_gregorian(date: DateTime<Utc>) -> Result<HebrewDate, ConversionError> {
-        if date < *crate::convert::year::backend::FIRST_RH + Duration::days(2 + 365) {
+        HebrewDate::from_days_since_epoch(days_since_epoch_of(date))
+    }
+
+    #[cfg(feature = "chrono")]
+    pub(crate) fn to_gregorian(self) -> DateTime<Utc> {
+        Utc.timestamp(EPOCH_TIMESTAMP + self.days_since_epoch() * 24 * 60 * 60, 0)
+    }
+
+    //Like to_gregorian, but returns YearTooLarge instead of panicking if the day is after the last
+    //time chrono supports.
+    #[cfg(feature = "chrono")]
+    pub(crate) fn try_to_gregorian(self) -> Result<DateTime<Utc>, ConversionError> {
+        self.days_since_epoch()
+            .checked_mul(24 * 60 * 60)
+            .and_then(|seconds| Utc.timestamp_opt(EPOCH_TIMESTAMP + seconds, 0).single())
+            .ok_or(ConversionError::YearTooLarge)
+    }
+
+    //The number of the month in its year, counting Tishrei as 1 (as in "5779-01-10").
+    pub(crate) fn month_number(self) -> u64 {
+        self.year.month_index(self.month) + 1
+    }
+
+    //The amount of days between the epoch and this day.
+    pub(crate) fn days_since_epoch(self) -> i64 {
+        self.year.days_since_epoch
+            + self.year.days_before_month(self.month) as i64
+            + i64::from(self.day.get())
+            - 1
+    }
+
+    pub(crate) fn from_days_since_epoch(days: i64) -> Result<HebrewDate, ConversionError> {
+        if days < YEAR_1_RH {
             return Err(ConversionError::YearTooSmall);
+        } else if days >= LAST_YEAR_END {
+            return Err(ConversionError::YearTooLarge);
         }
-        let days_since_first_rh =
-            ((date - *crate::convert::year::backend::FIRST_RH).num_days() + 2) as u64;
+        let hebrew_year = HebrewYear::new(day_of_last_rh(days))?;
+        Ok(hebrew_year.get_hebrewdate_from_days_after_rh(days))
+    }
 
-        let hebrew_year = HebrewYear::new(crate::convert::year::backend::day_of_last_rh(
-            days_since_first_rh,
-        ))
-        .unwrap();
-        Ok(hebrew_year.get_hebrewdate_from_days_after_rh(days_since_first_rh))
+    /// Returns the date a given amount of days after this one (or before it, if `days` is
+    /// negative), or None if the result is before the year 1.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let rh = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())?;
+    /// assert_eq!(
+    ///     rh.checked_add_days(-1),
+    ///     Some(HebrewDate::from_ymd(5778, HebrewMonth::Elul, NonZeroI8::new(29).unwrap())?)
+    /// );
+    /// assert_eq!(
+    ///     rh.checked_add_days(40),
+    ///     Some(HebrewDate::from_ymd(5779, HebrewMonth::Cheshvan, NonZeroI8::new(11).unwrap())?)
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn checked_add_days(self, days: i64) -> Option<HebrewDate> {
+        let new_day = self.days_since_epoch().checked_add(days)?;
+        //Don't bother building a new HebrewYear if we stay in the same year.
+        if new_day >= self.year.days_since_epoch
+            && new_day < self.year.days_since_epoch + self.year.year_len as i64
+        {
+            Some(self.year.get_hebrewdate_from_days_after_rh(new_day))
+        } else {
+            HebrewDate::from_days_since_epoch(new_day).ok()
+        }
     }
 
-    pub(crate) fn to_gregorian(&self) -> DateTime<Utc> {
-        let amnt_days_between_rh_and_epoch = self.year.days_since_epoch;
-        let sched = self.year.sched;
-        let mut amnt_days_in_month: u16 = 0;
-        if self.month != HebrewMonth::Tishrei {
-            for item in sched.iter().take(self.month as usize) {
-                amnt_days_in_month += u16::from(*item);
-            }
+    /// Returns the same day of the month a given amount of months after this one (or before it, if
+    /// `months` is negative).
+    ///
+    /// Months are counted as they are in the calendar, so one month after the 10th of Shvat is
+    /// the 10th of Adar in a regular year, and the 10th of Adar 1 in a leap year.
+    ///
+    /// # Arguments
+    ///
+    /// `months` - The amount of months to move.
+    ///
+    /// `policy` - What to do if the target month doesn't have a 30th day. `policy.leap_adar` isn't
+    /// used, as counting months never needs to choose between Adar 1 and Adar 2.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The result is before the year 1.
+    /// * `YearTooLarge` - The result is after the last year a HebrewYear can hold.
+    /// * `TooManyDaysInMonth` - The target month is too short and `policy.day_overflow` is `Fail`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let day = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(30).unwrap())?;
+    /// assert_eq!(
+    ///     day.add_months(1, ArithmeticPolicy::default())?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Cheshvan, NonZeroI8::new(30).unwrap())?
+    /// );
+    /// assert_eq!(
+    ///     day.add_months(13, ArithmeticPolicy::default())?, // 5779 is a leap year
+    ///     HebrewDate::from_ymd(5780, HebrewMonth::Tishrei, NonZeroI8::new(30).unwrap())?
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn add_months(
+        self,
+        months: i64,
+        policy: ArithmeticPolicy,
+    ) -> Result<HebrewDate, ConversionError> {
+        let cur_month =
+            (months_before_year(self.year.year) + self.year.month_index(self.month)) as i64;
+        let new_month = cur_month
+            .checked_add(months)
+            .ok_or(ConversionError::YearTooLarge)?;
+        if new_month < 0 {
+            return Err(ConversionError::YearTooSmall);
+        } else if new_month as u64 >= months_before_year(LAST_YEAR + 1) {
+            return Err(ConversionError::YearTooLarge);
         }
+        let new_month = new_month as u64;
+        let hebrew_year = HebrewYear::new(year_of_month(new_month))?;
+        let month = hebrew_year.month_from_index(new_month - months_before_year(hebrew_year.year));
+        HebrewDate::with_day_overflow(hebrew_year, month, self.day, policy.day_overflow)
+    }
 
-        let amnt_days =
-            amnt_days_between_rh_and_epoch + u64::from(amnt_days_in_month) + self.day.get() as u64
-                - 1;
-        *crate::convert::year::backend::EPOCH + Duration::days(amnt_days as i64)
+    /// Returns the same day and month a given amount of years after this one (or before it, if
+    /// `years` is negative).
+    ///
+    /// # Arguments
+    ///
+    /// `years` - The amount of years to move.
+    ///
+    /// `policy` - What to do if the target month doesn't have a 30th day, and which Adar to use
+    /// when moving a day in Adar into a leap year. A day in Adar 1 or Adar 2 moved into a regular
+    /// year always ends up in Adar.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The result is before the year 1.
+    /// * `YearTooLarge` - The result is after the last year a HebrewYear can hold.
+    /// * `TooManyDaysInMonth` - The target month is too short and `policy.day_overflow` is `Fail`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let purim = HebrewDate::from_ymd(5778, HebrewMonth::Adar, NonZeroI8::new(14).unwrap())?;
+    /// let policy = ArithmeticPolicy {
+    ///     day_overflow: DayOverflow::Fail,
+    ///     leap_adar: LeapAdar::Adar2,
+    /// };
+    /// assert_eq!(
+    ///     purim.add_years(1, policy)?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(14).unwrap())?
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn add_years(
+        self,
+        years: i64,
+        policy: ArithmeticPolicy,
+    ) -> Result<HebrewDate, ConversionError> {
+        let new_year = (self.year.year as i64)
+            .checked_add(years)
+            .ok_or(ConversionError::YearTooLarge)?;
+        if new_year < 1 {
+            return Err(ConversionError::YearTooSmall);
+        }
+        let hebrew_year = HebrewYear::new(new_year as u64)?;
+        let month = match (self.month, hebrew_year.is_leap_year()) {
+            (HebrewMonth::Adar, true) => match policy.leap_adar {
+                LeapAdar::Adar1 => HebrewMonth::Adar1,
+                LeapAdar::Adar2 => HebrewMonth::Adar2,
+            },
+            (HebrewMonth::Adar1, false) | (HebrewMonth::Adar2, false) => HebrewMonth::Adar,
+            (month, _) => month,
+        };
+        HebrewDate::with_day_overflow(hebrew_year, month, self.day, policy.day_overflow)
     }
+
+    fn with_day_overflow(
+        hebrew_year: HebrewYear,
+        month: HebrewMonth,
+        day: NonZeroI8,
+        day_overflow: DayOverflow,
+    ) -> Result<HebrewDate, ConversionError> {
+        let days_in_month = hebrew_year.sched[month as usize];
+        if day.get() as u8 <= days_in_month {
+            return hebrew_year.get_hebrew_date(month, day);
+        }
+        match day_overflow {
+            DayOverflow::Clamp => {
+                hebrew_year.get_hebrew_date(month, NonZeroI8::new(days_in_month as i8).unwrap())
+            }
+            DayOverflow::Carry => hebrew_year
+                .get_hebrew_date(month, NonZeroI8::new(1).unwrap())?
+                .checked_add_days(i64::from(day.get()) - 1)
+                .ok_or(ConversionError::YearTooSmall),
+            DayOverflow::Fail => Err(ConversionError::TooManyDaysInMonth(days_in_month)),
+        }
+    }
+
     ///Get the Hebrew day of month.
     #[inline]
-    pub fn day(&self) -> NonZeroI8 {
+    pub const fn day(&self) -> NonZeroI8 {
         self.day
     }
 
     ///Get the Hebrew month of year
     #[inline]
-    pub fn month(&self) -> HebrewMonth {
+    pub const fn month(&self) -> HebrewMonth {
         self.month
     }
 
     ///Get the Hebrew year.
-
     #[inline]
-    pub fn year(&self) -> u64 {
+    pub const fn year(&self) -> u64 {
         self.year.year
     }
+
+    // The HebrewYear which this date is in.
+    #[inline]
+    pub(crate) const fn hebrew_year(&self) -> HebrewYear {
+        self.year
+    }
+
+    /// Returns the day of the week.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(yom_kippur.weekday(), Day::Wednesday);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn weekday(&self) -> Day {
+        day_of_week(self.days_since_epoch())
+    }
+
+    /// Returns the first date after this one which falls out on the given day of the week, or None
+    /// if it's out of range.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(
+    ///     yom_kippur.next_weekday(Day::Shabbos),
+    ///     Some(HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(13).unwrap())?)
+    /// );
+    /// assert_eq!(
+    ///     yom_kippur.next_weekday(Day::Wednesday),
+    ///     Some(HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(17).unwrap())?)
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn next_weekday(&self, day: Day) -> Option<HebrewDate> {
+        let amnt_days = (day as i64 - self.weekday() as i64 + 6) % 7 + 1;
+        self.checked_add_days(amnt_days)
+    }
+
+    /// Returns the last date before this one which fell out on the given day of the week, or None
+    /// if it's before the year 1.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(
+    ///     yom_kippur.previous_weekday(Day::Shabbos),
+    ///     Some(HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(6).unwrap())?)
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn previous_weekday(&self, day: Day) -> Option<HebrewDate> {
+        let amnt_days = (self.weekday() as i64 - day as i64 + 6) % 7 + 1;
+        self.checked_add_days(-amnt_days)
+    }
+
+    /// Returns the amount of days from this date until `other`. This is negative if `other` is
+    /// before this date.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let rh = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())?;
+    /// let pesach = HebrewDate::from_ymd(5779, HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())?;
+    /// assert_eq!(rh.days_until(pesach), 222);
+    /// assert_eq!(pesach.days_until(rh), -222);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn days_until(&self, other: HebrewDate) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    /// Returns the day of the year, counting the first day of Rosh Hashana as 1.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(yom_kippur.day_of_year(), 10);
+    /// let last_day = HebrewDate::from_ymd(5779, HebrewMonth::Elul, NonZeroI8::new(29).unwrap())?;
+    /// assert_eq!(last_day.day_of_year(), 385);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn day_of_year(&self) -> u64 {
+        self.year.days_before_month(self.month) + self.day.get() as u64
+    }
+
+    /// Returns the week of the year.
+    ///
+    /// Weeks start on Sunday, and the week in which Rosh Hashana falls out is week 1. So if Rosh
+    /// Hashana is on a Thursday, the following Sunday starts week 2.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// // Rosh Hashana 5779 was on a Monday.
+    /// let shabbos = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(6).unwrap())?;
+    /// assert_eq!(shabbos.week_of_year(), 1);
+    /// let sunday = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(7).unwrap())?;
+    /// assert_eq!(sunday.week_of_year(), 2);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn week_of_year(&self) -> u64 {
+        (self.day_of_year() - 1 + self.year.day_of_rh as u64) / 7 + 1
+    }
+
+    /// Returns the amount of days between the epoch and this date.
+    ///
+    /// The epoch is the Hebrew day starting on the evening of September 21, 2 CE, so the number
+    /// is only useful when compared to other day numbers, such as ones returned by this function
+    /// or passed to `from_day_number`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let date = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())?;
+    /// assert_eq!(HebrewDate::from_day_number(date.day_number() + 29)?.month(), HebrewMonth::Tishrei);
+    /// assert_eq!(HebrewDate::from_day_number(date.day_number() + 30)?.month(), HebrewMonth::Cheshvan);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn day_number(&self) -> i64 {
+        self.days_since_epoch()
+    }
+
+    /// Returns the HebrewDate of a day number returned by `day_number`.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The day is before the year 1.
+    /// * `YearTooLarge` - The day is after the last year a HebrewYear can hold.
+    pub fn from_day_number(day_number: i64) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_days_since_epoch(day_number)
+    }
+
+    /// Returns the Rata Die (fixed day number) of the civil day on which the daytime of this
+    /// HebrewDate falls out. Rata Die 1 is the 1st of January, 1 CE in the proleptic Gregorian
+    /// calendar.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(yom_kippur.to_rata_die(), 736_956);
+    /// assert_eq!(HebrewDate::from_rata_die(736_956)?, yom_kippur);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn to_rata_die(&self) -> i64 {
+        self.days_since_epoch() + EPOCH_RATA_DIE
+    }
+
+    /// Returns the HebrewDate whose daytime falls out on the given Rata Die.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The day is before the year 1.
+    /// * `YearTooLarge` - The day is after the last year a HebrewYear can hold.
+    pub fn from_rata_die(rata_die: i64) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_days_since_epoch(
+            rata_die
+                .checked_sub(EPOCH_RATA_DIE)
+                .ok_or(ConversionError::YearTooSmall)?,
+        )
+    }
+
+    /// Returns the Julian Day Number of this HebrewDate.
+    ///
+    /// The Julian Day Number is the number of the Julian Day which starts at noon of the civil day
+    /// on which the daytime of this HebrewDate falls out.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+    /// assert_eq!(yom_kippur.to_jdn(), 2_458_381);
+    /// assert_eq!(HebrewDate::from_jdn(2_458_381)?, yom_kippur);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn to_jdn(&self) -> i64 {
+        self.to_rata_die() + JDN_OF_RATA_DIE_0
+    }
+
+    /// Returns the HebrewDate of a Julian Day Number returned by `to_jdn`.
+    ///
+    /// # Error Values
+    /// * `YearTooSmall` - The day is before the year 1.
+    /// * `YearTooLarge` - The day is after the last year a HebrewYear can hold.
+    pub fn from_jdn(jdn: i64) -> Result<HebrewDate, ConversionError> {
+        HebrewDate::from_rata_die(
+            jdn.checked_sub(JDN_OF_RATA_DIE_0)
+                .ok_or(ConversionError::YearTooSmall)?,
+        )
+    }
 }
 
 mod tests {
+    #[cfg(feature = "chrono")]
     #[test]
     fn get_year() {
         use super::*;
-        use chrono::prelude::*;
         for j in 0..100 {
             let mut original_day = Utc.ymd(16 + j, 10, 4).and_hms(18, 0, 0);
             for _i in 1..366 {
@@ -191,6 +748,238 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn add_days_matches_gregorian() {
+        use super::*;
+        let start =
+            HebrewDate::from_ymd(5700, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        for i in (-20000..20000).step_by(37) {
+            let moved = start + Duration::days(i);
+            assert_eq!(
+                moved.to_gregorian(),
+                start.to_gregorian() + Duration::days(i)
+            );
+            assert_eq!((moved - start).num_days(), i);
+            assert_eq!(moved - Duration::days(i), start);
+        }
+        assert_eq!(start.checked_add_days(-(1 << 40)), None);
+    }
+
+    #[test]
+    fn add_months_across_adar() {
+        use super::*;
+        let policy = ArithmeticPolicy::default();
+        let date =
+            HebrewDate::from_ymd(5778, HebrewMonth::Shvat, NonZeroI8::new(10).unwrap()).unwrap();
+        assert_eq!(
+            date.add_months(1, policy).unwrap(),
+            HebrewDate::from_ymd(5778, HebrewMonth::Adar, NonZeroI8::new(10).unwrap()).unwrap()
+        );
+        assert_eq!(
+            date.add_months(2, policy).unwrap(),
+            HebrewDate::from_ymd(5778, HebrewMonth::Nissan, NonZeroI8::new(10).unwrap()).unwrap()
+        );
+        //5779 is a leap year
+        assert_eq!(
+            date.add_months(13, policy).unwrap(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar1, NonZeroI8::new(10).unwrap()).unwrap()
+        );
+        assert_eq!(
+            date.add_months(14, policy).unwrap(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(10).unwrap()).unwrap()
+        );
+        assert_eq!(
+            date.add_months(15, policy)
+                .unwrap()
+                .add_months(-15, policy)
+                .unwrap(),
+            date
+        );
+        for i in -300..300 {
+            assert_eq!(
+                date.add_months(i, policy)
+                    .unwrap()
+                    .add_months(-i, policy)
+                    .unwrap(),
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn add_with_day_overflow() {
+        use super::*;
+        //Cheshvan 5780 has 30 days, Cheshvan 5781 has 29.
+        let date =
+            HebrewDate::from_ymd(5780, HebrewMonth::Cheshvan, NonZeroI8::new(30).unwrap()).unwrap();
+        let mut policy = ArithmeticPolicy::default();
+        assert_eq!(
+            date.add_years(1, policy).unwrap(),
+            HebrewDate::from_ymd(5781, HebrewMonth::Cheshvan, NonZeroI8::new(29).unwrap()).unwrap()
+        );
+        policy.day_overflow = DayOverflow::Carry;
+        assert_eq!(
+            date.add_years(1, policy).unwrap(),
+            HebrewDate::from_ymd(5781, HebrewMonth::Kislev, NonZeroI8::new(1).unwrap()).unwrap()
+        );
+        policy.day_overflow = DayOverflow::Fail;
+        assert_eq!(
+            date.add_years(1, policy),
+            Err(ConversionError::TooManyDaysInMonth(29))
+        );
+        assert_eq!(
+            date.add_months(12, policy),
+            Err(ConversionError::TooManyDaysInMonth(29))
+        );
+    }
+
+    #[test]
+    fn add_years_maps_adar() {
+        use super::*;
+        let adar =
+            HebrewDate::from_ymd(5778, HebrewMonth::Adar, NonZeroI8::new(7).unwrap()).unwrap();
+        let mut policy = ArithmeticPolicy {
+            day_overflow: DayOverflow::Clamp,
+            leap_adar: LeapAdar::Adar1,
+        };
+        assert_eq!(
+            adar.add_years(1, policy).unwrap(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar1, NonZeroI8::new(7).unwrap()).unwrap()
+        );
+        policy.leap_adar = LeapAdar::Adar2;
+        assert_eq!(
+            adar.add_years(1, policy).unwrap(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(7).unwrap()).unwrap()
+        );
+        let adar1 =
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar1, NonZeroI8::new(30).unwrap()).unwrap();
+        assert_eq!(
+            adar1.add_years(1, policy).unwrap(),
+            HebrewDate::from_ymd(5780, HebrewMonth::Adar, NonZeroI8::new(29).unwrap()).unwrap()
+        );
+        assert_eq!(
+            adar1.add_years(-5779, policy),
+            Err(ConversionError::YearTooSmall)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn day_of_year_and_week() {
+        use super::*;
+        use chrono::prelude::*;
+        for year in 5700..5800 {
+            let hebrew_year = HebrewYear::new(year).unwrap();
+            let rh = hebrew_year
+                .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+                .unwrap();
+            let mut week = 1;
+            for i in 0..hebrew_year.len() as i64 {
+                let day = rh + Duration::days(i);
+                assert_eq!(day.day_of_year(), i as u64 + 1);
+                assert_eq!(rh.days_until(day), i);
+                if i > 0 && day.to_gregorian().weekday() == Weekday::Sat {
+                    week += 1;
+                }
+                assert_eq!(day.week_of_year(), week);
+                assert_eq!(HebrewDate::from_day_number(day.day_number()).unwrap(), day);
+            }
+        }
+        let first_day =
+            HebrewDate::from_ymd(1, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        assert_eq!(
+            HebrewDate::from_day_number(first_day.day_number() - 1),
+            Err(ConversionError::YearTooSmall)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn weekday_matches_gregorian() {
+        use super::*;
+        use chrono::prelude::*;
+        let start =
+            HebrewDate::from_ymd(3764, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        for day in HebrewDateRange::new(start + Duration::days(7), start + Duration::days(100_000))
+            .step_by(13)
+        {
+            //The Hebrew day starts the evening before its Gregorian day.
+            let weekday = (day.to_gregorian() + Duration::days(1)).weekday();
+            assert_eq!(day.weekday() as u32, weekday.num_days_from_sunday());
+            for i in Day::all() {
+                let next = day.next_weekday(i).unwrap();
+                assert_eq!(next.weekday(), i);
+                assert!(day.days_until(next) >= 1 && day.days_until(next) <= 7);
+                let prev = day.previous_weekday(i).unwrap();
+                assert_eq!(prev.weekday(), i);
+                assert!(prev.days_until(day) >= 1 && prev.days_until(day) <= 7);
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn rata_die_and_jdn_match_chrono() {
+        use super::*;
+        use chrono::prelude::*;
+        let first_day =
+            HebrewDate::from_ymd(1, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        // The first day of the year 1 was Monday, the 7th of October, 3761 BCE (Julian).
+        assert_eq!(first_day.to_rata_die(), -1_373_427);
+        assert_eq!(first_day.to_jdn(), 347_998);
+        assert_eq!(
+            HebrewDate::from_rata_die(-1_373_428),
+            Err(ConversionError::YearTooSmall)
+        );
+        assert_eq!(
+            HebrewDate::from_jdn(347_997),
+            Err(ConversionError::YearTooSmall)
+        );
+        for day in
+            HebrewDateRange::new(first_day, first_day + Duration::days(3_000_000)).step_by(101)
+        {
+            let civil_date = NaiveDate::try_from(day).unwrap();
+            assert_eq!(day.to_rata_die(), civil_date.num_days_from_ce() as i64);
+            assert_eq!(HebrewDate::from_rata_die(day.to_rata_die()), Ok(day));
+            assert_eq!(HebrewDate::from_jdn(day.to_jdn()), Ok(day));
+            // Julian Day 0 was a Monday.
+            assert_eq!((day.to_jdn() + 1).rem_euclid(7), day.weekday() as i64);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn early_years() {
+        use super::*;
+        use std::convert::TryFrom;
+        // Rosh Hashana of the year 1 was on Monday, the 7th of October, 3761 BCE in the Julian
+        // calendar, which is the 7th of September in the proleptic Gregorian calendar.
+        let first_day =
+            HebrewDate::from_ymd(1, HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap()).unwrap();
+        assert_eq!(first_day.weekday(), Day::Monday);
+        assert_eq!(
+            first_day.to_gregorian(),
+            Utc.ymd(-3760, 9, 6).and_hms(18, 0, 0)
+        );
+        assert_eq!(first_day.checked_add_days(-1), None);
+        assert_eq!(first_day.previous_weekday(Day::Sunday), None);
+        assert_eq!(
+            HebrewDate::try_from(Utc.ymd(-3760, 9, 6).and_hms(17, 59, 59)),
+            Err(ConversionError::YearTooSmall)
+        );
+        let mut original_day = first_day.to_gregorian() + Duration::days(1);
+        for _ in 0..20000 {
+            let h_day = HebrewDate::from_gregorian(original_day).unwrap();
+            assert_eq!(h_day.to_gregorian(), original_day);
+            assert_eq!(
+                HebrewDate::from_gregorian(original_day - Duration::seconds(1)).unwrap(),
+                h_day - Duration::days(1)
+            );
+            original_day = original_day + Duration::days(97);
+        }
+    }
+
     #[test]
     fn from_ymd_negative() {
         use crate::prelude::*;
@@ -0,0 +1,156 @@
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+use crate::convert::HebrewDate;
+
+/// An iterator over every day between two HebrewDates, including both of them.
+///
+/// The HebrewYear of the current date is reused for every day in that year, so iterating is
+/// much faster than building each HebrewDate on its own.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+/// use heca_lib::prelude::*;
+/// use heca_lib::{HebrewDate, HebrewDateRange};
+///
+/// let start = HebrewDate::from_ymd(5779, HebrewMonth::Elul, NonZeroI8::new(28).unwrap())?;
+/// let end = HebrewDate::from_ymd(5780, HebrewMonth::Tishrei, NonZeroI8::new(2).unwrap())?;
+/// let range = HebrewDateRange::from(start..=end);
+/// assert_eq!(range.len(), 4);
+/// assert_eq!(
+///     range.map(|x| x.day().get()).collect::<Vec<_>>(),
+///     vec![28, 29, 1, 2]
+/// );
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HebrewDateRange {
+    front: HebrewDate,
+    back: HebrewDate,
+    remaining: usize,
+}
+
+impl HebrewDateRange {
+    /// Returns an iterator from `start` until `end`, including both. If `end` is before `start`,
+    /// the iterator is empty.
+    pub fn new(start: HebrewDate, end: HebrewDate) -> HebrewDateRange {
+        let remaining = if end < start {
+            0
+        } else {
+            start.days_until(end) as usize + 1
+        };
+        HebrewDateRange {
+            front: start,
+            back: end,
+            remaining,
+        }
+    }
+}
+
+impl From<RangeInclusive<HebrewDate>> for HebrewDateRange {
+    fn from(range: RangeInclusive<HebrewDate>) -> HebrewDateRange {
+        let (start, end) = range.into_inner();
+        HebrewDateRange::new(start, end)
+    }
+}
+
+impl Iterator for HebrewDateRange {
+    type Item = HebrewDate;
+    fn next(&mut self) -> Option<HebrewDate> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let cur = self.front;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            //There's a day after this one, so this can't go out of range.
+            self.front = cur.checked_add_days(1).unwrap();
+        }
+        Some(cur)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for HebrewDateRange {
+    fn next_back(&mut self) -> Option<HebrewDate> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let cur = self.back;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            //There's a day before this one, so this can't go out of range.
+            self.back = cur.checked_add_days(-1).unwrap();
+        }
+        Some(cur)
+    }
+}
+
+impl ExactSizeIterator for HebrewDateRange {}
+
+impl FusedIterator for HebrewDateRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::HebrewYear;
+    use std::num::NonZeroI8;
+
+    #[test]
+    fn range_goes_over_years() {
+        let start =
+            HebrewDate::from_ymd(5700, HebrewMonth::Shvat, NonZeroI8::new(1).unwrap()).unwrap();
+        let end =
+            HebrewDate::from_ymd(5720, HebrewMonth::Elul, NonZeroI8::new(29).unwrap()).unwrap();
+        let range = HebrewDateRange::new(start, end);
+        assert_eq!(range.len() as i64, start.days_until(end) + 1);
+        let mut prev = None;
+        for (i, day) in range.enumerate() {
+            assert_eq!(start.days_until(day), i as i64);
+            if let Some(prev) = prev {
+                assert!(prev < day);
+            }
+            prev = Some(day);
+        }
+        assert_eq!(prev, Some(end));
+    }
+
+    #[test]
+    fn range_is_double_ended() {
+        let start =
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar1, NonZeroI8::new(1).unwrap()).unwrap();
+        let end =
+            HebrewDate::from_ymd(5781, HebrewMonth::Nissan, NonZeroI8::new(1).unwrap()).unwrap();
+        let forwards = HebrewDateRange::new(start, end).collect::<Vec<_>>();
+        let mut backwards = HebrewDateRange::new(start, end).rev().collect::<Vec<_>>();
+        backwards.reverse();
+        assert_eq!(forwards, backwards);
+
+        let mut range = HebrewDateRange::new(start, end);
+        assert_eq!(range.next(), Some(start));
+        assert_eq!(range.next_back(), Some(end));
+        assert_eq!(range.len(), forwards.len() - 2);
+        assert_eq!(range.next_back(), forwards.get(forwards.len() - 2).copied());
+    }
+
+    #[test]
+    fn empty_range() {
+        let year = HebrewYear::new(5779).unwrap();
+        let start = year
+            .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(2).unwrap())
+            .unwrap();
+        let end = year
+            .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+            .unwrap();
+        let mut range = HebrewDateRange::new(start, end);
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(HebrewDateRange::new(start, start).count(), 1);
+    }
+}
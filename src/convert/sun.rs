@@ -0,0 +1,137 @@
+use chrono::prelude::*;
+use chrono::Duration;
+
+// This is the NOAA solar calculator, as documented in
+// https://gml.noaa.gov/grad/solcalc/calcdetails.html . It's accurate to about a minute for
+// latitudes between +/- 72 degrees.
+
+/// The zenith of the center of the sun at sunrise and sunset, accounting for refraction and the
+/// radius of the sun.
+pub(crate) const SUNSET_ZENITH: f64 = 90.833;
+
+const JULIAN_DAY_OF_UNIX_EPOCH: f64 = 2_440_587.5;
+const JULIAN_DAY_OF_J2000: f64 = 2_451_545.0;
+
+// Returns the equation of time (in minutes) and the declination of the sun (in degrees) at a
+// given Julian day.
+fn equation_of_time_and_declination(julian_day: f64) -> (f64, f64) {
+    let t = (julian_day - JULIAN_DAY_OF_J2000) / 36525.0;
+    let mean_long = (280.46646 + t * (36000.76983 + t * 0.000_303_2)) % 360.0;
+    let mean_anom = 357.52911 + t * (35999.05029 - 0.000_153_7 * t);
+    let eccent = 0.016_708_634 - t * (0.000_042_037 + 0.000_000_126_7 * t);
+    let eq_of_ctr = mean_anom.to_radians().sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * mean_anom).to_radians().sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * mean_anom).to_radians().sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let app_long = mean_long + eq_of_ctr - 0.00569 - 0.00478 * omega.sin();
+    let mean_obliq =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliq = (mean_obliq + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliq.sin() * app_long.to_radians().sin())
+        .asin()
+        .to_degrees();
+    let y = (obliq / 2.0).tan().powi(2);
+    let l0 = mean_long.to_radians();
+    let m = mean_anom.to_radians();
+    let eq_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccent * m.sin()
+            + 4.0 * eccent * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccent * eccent * (2.0 * m).sin())
+        .to_degrees();
+    (eq_of_time, declination)
+}
+
+// Returns the amount of minutes after midnight UTC that the sun is at the given zenith, or None if
+// it never gets there on that day.
+fn minutes_at_zenith(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    zenith: f64,
+    rising: bool,
+) -> Option<f64> {
+    let midnight = date
+        .signed_duration_since(NaiveDate::from_ymd(1970, 1, 1))
+        .num_days() as f64
+        + JULIAN_DAY_OF_UNIX_EPOCH;
+    //Start with local noon, and then run it again with the time we got to be more precise.
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..2 {
+        let (eq_of_time, declination) =
+            equation_of_time_and_declination(midnight + minutes / 1440.0);
+        let (lat, decl) = (latitude.to_radians(), declination.to_radians());
+        let cos_hour_angle =
+            zenith.to_radians().cos() / (lat.cos() * decl.cos()) - lat.tan() * decl.tan();
+        if !(-1.0..=1.0).contains(&cos_hour_angle) {
+            return None;
+        }
+        let hour_angle = cos_hour_angle.acos().to_degrees();
+        let noon = 720.0 - 4.0 * longitude - eq_of_time;
+        minutes = if rising {
+            noon - 4.0 * hour_angle
+        } else {
+            noon + 4.0 * hour_angle
+        };
+    }
+    Some(minutes)
+}
+
+/// Returns when the sun is at the given zenith on the given day, at the given location (positive
+/// latitudes are north and positive longitudes are east).
+pub(crate) fn time_at_zenith(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    zenith: f64,
+    rising: bool,
+) -> Option<DateTime<Utc>> {
+    minutes_at_zenith(date, latitude, longitude, zenith, rising).map(|minutes| {
+        Utc.from_utc_datetime(&date.and_hms(0, 0, 0))
+            + Duration::milliseconds((minutes * 60_000.0).round() as i64)
+    })
+}
+
+/// Returns sunset of the given day, or None if the sun doesn't set on that day.
+pub(crate) fn sunset(date: NaiveDate, latitude: f64, longitude: f64) -> Option<DateTime<Utc>> {
+    time_at_zenith(date, latitude, longitude, SUNSET_ZENITH, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: DateTime<Utc>, b: DateTime<Utc>) {
+        assert!(
+            (a - b).num_seconds().abs() <= 90,
+            "{} and {} are more than 90 seconds apart",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn sunset_matches_noaa() {
+        // Jerusalem
+        assert_close(
+            sunset(NaiveDate::from_ymd(2018, 9, 9), 31.778, 35.2354).unwrap(),
+            Utc.ymd(2018, 9, 9).and_hms(15, 53, 0),
+        );
+        // New York. Sunset is after midnight UTC.
+        assert_close(
+            sunset(NaiveDate::from_ymd(2019, 6, 21), 40.7128, -74.006).unwrap(),
+            Utc.ymd(2019, 6, 22).and_hms(0, 31, 0),
+        );
+        // Sydney
+        assert_close(
+            sunset(NaiveDate::from_ymd(2020, 1, 1), -33.8688, 151.2093).unwrap(),
+            Utc.ymd(2020, 1, 1).and_hms(9, 10, 0),
+        );
+    }
+
+    #[test]
+    fn no_sunset_near_the_poles() {
+        assert_eq!(sunset(NaiveDate::from_ymd(2019, 6, 21), 80.0, 15.0), None);
+        assert_eq!(sunset(NaiveDate::from_ymd(2019, 12, 21), 80.0, 15.0), None);
+    }
+}
@@ -1,5 +1,7 @@
-use std::convert::{TryFrom, TryInto};
+use core::convert::TryFrom;
+use core::convert::TryInto;
 
+use serde::{Deserialize, Serialize};
 use smallvec::*;
 
 use crate::convert::year::backend::{CHALAKIM_BETWEEN_MOLAD, FIRST_MOLAD};
@@ -8,21 +10,25 @@ use crate::holidays::get_chol_list;
 use crate::holidays::get_shabbos_list;
 use crate::holidays::get_special_parsha_list;
 use crate::holidays::get_yt_list;
-use chrono::{Duration, Utc};
-use std::num::NonZeroI8;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use core::num::NonZeroI8;
 
 pub(crate) mod backend;
 
 use crate::convert::year::backend::{
-    get_rosh_hashana, months_per_year, return_year_sched, CHALAKIM_PER_HOUR, EPOCH, FIRST_YEAR,
-    YEAR_SCHED,
+    months_per_year, return_year_sched, rosh_hashana, CHALAKIM_PER_HOUR, LAST_YEAR, YEAR_SCHED,
 };
 use crate::prelude::HebrewMonth::{Adar, Adar1, Adar2};
 use crate::prelude::{ConversionError, HebrewMonth, Molad};
 
 /// HebrewYear holds data on a given year. It's faster to get multiple HebrewDates from
 /// an existing HebrewYear rather than generating each one on its own.
-#[derive(Copy, Clone, Debug)]
+///
+/// It's serialized as the number of the year. Deserializing it fails for the same years as
+/// `HebrewYear::new` (the year 0, and years too large for their Molad to be calculated).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u64", into = "u64")]
 pub struct HebrewYear {
     pub(crate) year: u64,
     pub(crate) day_of_rh: Day,
@@ -30,36 +36,129 @@ pub struct HebrewYear {
     pub(crate) months_per_year: u64,
     pub(crate) sched: [u8; 14],
     pub(crate) year_len: u64,
-    pub(crate) days_since_epoch: u64,
-    pub(crate) chalakim_since_epoch: u64,
+    pub(crate) days_since_epoch: i64,
+    pub(crate) chalakim_since_epoch: i64,
+}
+
+/// The same as `HebrewYear::new`.
+impl TryFrom<u64> for HebrewYear {
+    type Error = ConversionError;
+    fn try_from(year: u64) -> Result<HebrewYear, ConversionError> {
+        HebrewYear::new(year)
+    }
+}
+
+/// The same as `HebrewYear::year`.
+impl From<HebrewYear> for u64 {
+    fn from(year: HebrewYear) -> u64 {
+        year.year
+    }
+}
+
+/// MonthInfo holds data on a month in a given year. It's returned by `HebrewYear::months()`.
+#[derive(Copy, Clone, Debug)]
+pub struct MonthInfo {
+    year: HebrewYear,
+    month: HebrewMonth,
+}
+
+impl MonthInfo {
+    /// Returns the month.
+    #[inline]
+    pub fn month(&self) -> HebrewMonth {
+        self.month
+    }
+
+    /// Returns the year this month is in.
+    #[inline]
+    pub fn year(&self) -> HebrewYear {
+        self.year
+    }
+
+    /// Returns the amount of days in this month (either 29 or 30).
+    #[inline]
+    pub fn days_in_month(&self) -> u8 {
+        self.year.sched[self.month as usize]
+    }
+
+    /// Returns the first day of the month.
+    #[inline]
+    pub fn first_day(&self) -> HebrewDate {
+        HebrewDate {
+            year: self.year,
+            month: self.month,
+            day: NonZeroI8::new(1).unwrap(),
+        }
+    }
+
+    /// Returns the last day of the month.
+    #[inline]
+    pub fn last_day(&self) -> HebrewDate {
+        HebrewDate {
+            year: self.year,
+            month: self.month,
+            day: NonZeroI8::new(self.days_in_month() as i8).unwrap(),
+        }
+    }
+
+    /// Returns an iterator over every day in the month.
+    #[inline]
+    pub fn days(&self) -> HebrewDateRange {
+        HebrewDateRange::new(self.first_day(), self.last_day())
+    }
+
+    /// Returns the Molad of the month.
+    #[inline]
+    pub fn molad(&self) -> Molad {
+        //The month always exists in this year, so this can't fail.
+        self.year.get_molad(self.month).unwrap()
+    }
 }
 
 impl HebrewYear {
     #[inline]
-    pub fn new(year: u64) -> Result<HebrewYear, ConversionError> {
+    pub const fn new(year: u64) -> Result<HebrewYear, ConversionError> {
         //! Returns a new HebrewYear on success or a ConversionError on failure.
         //!
         //! # Arguments
         //!
         //! `year` - The Hebrew year
         //!
-        if year < FIRST_YEAR + 1 {
+        //! # Error Values
+        //!
+        //! * `YearTooSmall` - The year is 0.
+        //! * `YearTooLarge` - The year is too large for its Molad to be calculated (it's after the
+        //!   year 974,245,223,496).
+        //!
+        //! This is a `const fn`, so a year can be built at compile time:
+        //!
+        //! ```
+        //! use heca_lib::prelude::*;
+        //! use heca_lib::HebrewYear;
+        //!
+        //! const YEAR: Result<HebrewYear, ConversionError> = HebrewYear::new(5779);
+        //! assert_eq!(YEAR?.len(), 385);
+        //! # Ok::<(),ConversionError>(())
+        //! ```
+        if year < 1 {
             Err(ConversionError::YearTooSmall)
+        } else if year > LAST_YEAR {
+            Err(ConversionError::YearTooLarge)
         } else {
-            let cur_rh = get_rosh_hashana(year);
-            let next_rh = get_rosh_hashana(year + 1);
+            let cur_rh = rosh_hashana(year);
+            let next_rh = rosh_hashana(year + 1);
             let days_since_epoch = cur_rh.0;
             let chalakim_since_epoch = cur_rh.2;
-            let year_len = next_rh.0 - cur_rh.0;
+            let year_len = (next_rh.0 - cur_rh.0) as u64;
             let months_per_year = months_per_year(year);
-            let sched = &YEAR_SCHED[return_year_sched(year_len)];
+            let sched = YEAR_SCHED[return_year_sched(year_len)];
 
             Ok(HebrewYear {
-                day_of_rh: get_rosh_hashana(year).1,
+                day_of_rh: cur_rh.1,
                 year,
-                day_of_next_rh: get_rosh_hashana(year + 1).1,
+                day_of_next_rh: next_rh.1,
                 months_per_year,
-                sched: sched.clone(),
+                sched,
                 days_since_epoch,
                 year_len,
                 chalakim_since_epoch,
@@ -76,7 +175,7 @@ impl HebrewYear {
     /// assert_eq!(HebrewYear::new(5779)?.is_leap_year(),true);
     /// # Ok::<(),ConversionError>(())
     /// ```
-    pub fn is_leap_year(&self) -> bool {
+    pub const fn is_leap_year(&self) -> bool {
         self.months_per_year == 13
     }
 
@@ -97,20 +196,20 @@ impl HebrewYear {
                     } else if self.sched[1] == 29 && self.sched[2] == 29 {
                         MonthSchedule::BaChaG
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 12 months, stars on Monday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Tuesday => {
                     if self.sched[1] == 29 && self.sched[2] == 30 {
                         MonthSchedule::GaChaH
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 12 months, starts on Tuesday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Thursday => {
@@ -119,10 +218,10 @@ impl HebrewYear {
                     } else if self.sched[1] == 30 && self.sched[2] == 30 {
                         MonthSchedule::HaShA
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 12 months, starts on Thursday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Shabbos => {
@@ -131,13 +230,13 @@ impl HebrewYear {
                     } else if self.sched[1] == 29 && self.sched[2] == 29 {
                         MonthSchedule::ZaChA
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 12 months, stars on Shabbos, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
-                x => panic!(format!("Rosh Hashana should never fall out on {:?}", x)),
+                x => panic!("Rosh Hashana should never fall out on {:?}", x),
             }
         } else {
             match self.day_of_rh {
@@ -147,20 +246,20 @@ impl HebrewYear {
                     } else if self.sched[1] == 29 && self.sched[2] == 29 {
                         MonthSchedule::BaChaH
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 13 months, stars on Monday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Tuesday => {
                     if self.sched[1] == 29 && self.sched[2] == 30 {
                         MonthSchedule::GaKaZ
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 13 months, starts on Tuesday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Thursday => {
@@ -169,10 +268,10 @@ impl HebrewYear {
                     } else if self.sched[1] == 29 && self.sched[2] == 29 {
                         MonthSchedule::HaChA
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 13 months, starts on Thursday, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
                 Day::Shabbos => {
@@ -181,13 +280,13 @@ impl HebrewYear {
                     } else if self.sched[1] == 29 && self.sched[2] == 29 {
                         MonthSchedule::ZaChaG
                     } else {
-                        panic!(format!(
+                        panic!(
                             "Year {} is 13 months, stars on Shabbos, yet has Cheshvan {} days and Kislev {} days",
                             self.year, self.sched[1], self.sched[2]
-                        ))
+                        )
                     }
                 }
-                x => panic!(format!("Rosh Hashana should never fall out on {:?}", x)),
+                x => panic!("Rosh Hashana should never fall out on {:?}", x),
             }
         }
     }
@@ -205,9 +304,129 @@ impl HebrewYear {
     /// # Ok::<(),ConversionError>(())
     /// ```
     #[inline]
-    pub fn year(&self) -> u64 {
+    pub const fn year(&self) -> u64 {
         self.year
     }
+
+    /// Returns the amount of days in the year.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    /// assert_eq!(HebrewYear::new(5779)?.len(), 385);
+    /// assert_eq!(HebrewYear::new(5780)?.len(), 355);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> u64 {
+        self.year_len
+    }
+
+    /// Returns the next year.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    /// assert_eq!(HebrewYear::new(5779)?.next()?.year(), 5780);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn next(&self) -> Result<HebrewYear, ConversionError> {
+        HebrewYear::new(self.year + 1)
+    }
+
+    /// Returns the previous year, or a ConversionError::YearTooSmall if this is the first year
+    /// supported.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    /// assert_eq!(HebrewYear::new(5779)?.prev()?.year(), 5778);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[inline]
+    pub fn prev(&self) -> Result<HebrewYear, ConversionError> {
+        HebrewYear::new(self.year - 1)
+    }
+
+    /// Returns the first day in the given month which falls out on the given day of the week.
+    ///
+    /// # Error Values
+    /// * `IsLeapYear` - The month is Adar, and this is a leap year.
+    /// * `IsNotLeapYear` - The month is Adar 1 or Adar 2, and this isn't a leap year.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::{HebrewDate, HebrewYear};
+    /// let year = HebrewYear::new(5779)?;
+    /// assert_eq!(
+    ///     year.first_weekday_of(HebrewMonth::Tishrei, Day::Shabbos)?,
+    ///     HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(6).unwrap())?
+    /// );
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn first_weekday_of(
+        &self,
+        month: HebrewMonth,
+        day: Day,
+    ) -> Result<HebrewDate, ConversionError> {
+        let first_day = self.get_hebrew_date(month, NonZeroI8::new(1).unwrap())?;
+        let amnt_days = (day as i8 - first_day.weekday() as i8 + 7) % 7;
+        self.get_hebrew_date(month, NonZeroI8::new(amnt_days + 1).unwrap())
+    }
+
+    /// Returns an iterator over every day in the year, from the first day of Rosh Hashana until
+    /// the last day of Elul.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    /// let year = HebrewYear::new(5779)?;
+    /// assert_eq!(year.days().count() as u64, year.len());
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn days(&self) -> HebrewDateRange {
+        let first_day = self.get_hebrewdate_from_days_after_rh(self.days_since_epoch);
+        let last_day = self
+            .get_hebrewdate_from_days_after_rh(self.days_since_epoch + self.year_len as i64 - 1);
+        HebrewDateRange::new(first_day, last_day)
+    }
+
+    /// Returns an iterator over the months of the year, in order.
+    ///
+    /// Adar 1 and Adar 2 are only returned in a leap year, and Adar is only returned in a regular
+    /// year.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    /// let months = HebrewYear::new(5779)?.months().collect::<Vec<_>>();
+    /// assert_eq!(months.len(), 13);
+    /// assert_eq!(months[5].month(), HebrewMonth::Adar1);
+    /// assert_eq!(months[5].days_in_month(), 30);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn months(&self) -> impl DoubleEndedIterator<Item = MonthInfo> + ExactSizeIterator {
+        let year = *self;
+        (0..self.months_per_year as u8).map(move |index| MonthInfo {
+            year,
+            month: year.month_from_index(u64::from(index)),
+        })
+    }
     /// Returns a HebrewDate from the current year and a supplied month and day.
     ///
     /// # Arguments:
@@ -234,7 +453,7 @@ impl HebrewYear {
     ///
     /// Day must be above zero. If it's below zero, the function returns TooManyDaysInMonth. In a future release, day will be a NonZeroU8 so that it will be impossible to supply a negative number.
     #[inline]
-    pub fn get_hebrew_date(
+    pub const fn get_hebrew_date(
         self,
         month: HebrewMonth,
         day: NonZeroI8,
@@ -242,8 +461,15 @@ impl HebrewYear {
         HebrewDate::from_ymd_internal(month, day, self)
     }
 
-    pub(crate) fn get_hebrewdate_from_days_after_rh(self, amnt_days: u64) -> HebrewDate {
-        let mut remainder = amnt_days - self.days_since_epoch;
+    //The month of the given index in HebrewMonth (not the index in this year, as in
+    //month_from_index).
+    fn month_from_u64(month: u64) -> HebrewMonth {
+        //There are only 14 months in sched, so this can't fail.
+        HebrewMonth::try_from(month as u8).unwrap()
+    }
+
+    pub(crate) fn get_hebrewdate_from_days_after_rh(self, amnt_days: i64) -> HebrewDate {
+        let mut remainder = (amnt_days - self.days_since_epoch) as u64;
         let mut month: u64 = 0;
         for days_in_month in self.sched.iter() {
             if remainder < u64::from(*days_in_month) {
@@ -254,10 +480,51 @@ impl HebrewYear {
         }
         HebrewDate {
             year: self,
-            month: HebrewMonth::from(month),
+            month: HebrewYear::month_from_u64(month),
             day: NonZeroI8::new((remainder + 1) as i8).unwrap(),
         }
     }
+
+    //The amount of days between Rosh Hashana and the first of the given month.
+    pub(crate) fn days_before_month(&self, month: HebrewMonth) -> u64 {
+        self.sched
+            .iter()
+            .take(month as usize)
+            .map(|x| u64::from(*x))
+            .sum()
+    }
+
+    //The position of the month in this year, counting Tishrei as 0. Adar is skipped in a leap
+    //year and Adar1 and Adar2 are skipped in a regular year.
+    pub(crate) fn month_index(&self, month: HebrewMonth) -> u64 {
+        let month = month as u64;
+        if self.is_leap_year() {
+            if month < Adar as u64 {
+                month
+            } else {
+                month - 1
+            }
+        } else if month <= Adar as u64 {
+            month
+        } else {
+            month - 2
+        }
+    }
+
+    //The inverse of month_index.
+    pub(crate) fn month_from_index(&self, index: u64) -> HebrewMonth {
+        if self.is_leap_year() {
+            if index < Adar as u64 {
+                HebrewYear::month_from_u64(index)
+            } else {
+                HebrewYear::month_from_u64(index + 1)
+            }
+        } else if index <= Adar as u64 {
+            HebrewYear::month_from_u64(index)
+        } else {
+            HebrewYear::month_from_u64(index + 2)
+        }
+    }
     /// Returns all the days when the Torah is read.
     ///
     /// # Arguments
@@ -315,16 +582,16 @@ impl HebrewYear {
     ) -> SmallVec<[TorahReadingDay; 256]> {
         let mut return_vec: SmallVec<[TorahReadingDay; 256]> = SmallVec::new();
         if yt_types.contains(&TorahReadingType::YomTov) {
-            return_vec.extend_from_slice(&get_yt_list(self.clone(), location));
+            return_vec.extend_from_slice(&get_yt_list(*self, location));
         }
         if yt_types.contains(&TorahReadingType::Chol) {
-            return_vec.extend_from_slice(&get_chol_list(self.clone()));
+            return_vec.extend_from_slice(&get_chol_list(*self));
         }
         if yt_types.contains(&TorahReadingType::Shabbos) {
-            return_vec.extend_from_slice(&get_shabbos_list(self.clone(), location));
+            return_vec.extend_from_slice(&get_shabbos_list(*self, location));
         }
         if yt_types.contains(&TorahReadingType::SpecialParsha) {
-            return_vec.extend_from_slice(&get_special_parsha_list(self.clone()));
+            return_vec.extend_from_slice(&get_special_parsha_list(*self));
         }
         return_vec
     }
@@ -373,47 +640,37 @@ impl HebrewYear {
         } * CHALAKIM_BETWEEN_MOLAD
             + self.chalakim_since_epoch
             + FIRST_MOLAD;
-        let minutes_since_epoch = (chalakim_since_epoch / (CHALAKIM_PER_HOUR / 60))
+        let minutes_since_epoch = chalakim_since_epoch.div_euclid(CHALAKIM_PER_HOUR / 60);
+        let remainder = chalakim_since_epoch
+            .rem_euclid(CHALAKIM_PER_HOUR / 60)
             .try_into()
             .unwrap();
-        let remainder = (chalakim_since_epoch % (CHALAKIM_PER_HOUR / 60))
-            .try_into()
-            .unwrap();
-        let day = EPOCH.clone() + Duration::minutes(minutes_since_epoch);
-        Ok(Molad { day, remainder })
+        Ok(Molad {
+            minutes_since_epoch,
+            remainder,
+        })
     }
 }
 
+#[cfg(feature = "chrono")]
 #[test]
 fn test_get_molad() {
     use chrono::prelude::*;
     let hebrew_year = HebrewYear::new(5780).unwrap();
     let p = hebrew_year.get_molad(HebrewMonth::Tishrei).unwrap();
-    assert_eq!(
-        p,
-        Molad {
-            day: Utc.ymd(2019, 9, 29).and_hms(5, 50, 0),
-            remainder: 5
-        }
-    );
+    assert_eq!(p.get_day_utc(), Utc.ymd(2019, 9, 29).and_hms(5, 50, 0));
+    assert_eq!(p.get_chalakim(), 5);
     let p = hebrew_year.get_molad(HebrewMonth::Cheshvan).unwrap();
-    assert_eq!(
-        p,
-        Molad {
-            day: Utc.ymd(2019, 10, 28).and_hms(18, 34, 0),
-            remainder: 6
-        }
-    );
+    assert_eq!(p.get_day_utc(), Utc.ymd(2019, 10, 28).and_hms(18, 34, 0));
+    assert_eq!(p.get_chalakim(), 6);
+    //The Hebrew day starts at 6:00 PM, so this is on Tuesday.
+    assert_eq!(p.weekday(), Day::Tuesday);
+    assert_eq!((p.hours(), p.minutes()), (0, 34));
 
     let hebrew_year = HebrewYear::new(5781).unwrap();
     let p = hebrew_year.get_molad(HebrewMonth::Elul).unwrap();
-    assert_eq!(
-        p,
-        Molad {
-            day: Utc.ymd(2021, 8, 8).and_hms(10, 43, 0),
-            remainder: 10
-        }
-    );
+    assert_eq!(p.get_day_utc(), Utc.ymd(2021, 8, 8).and_hms(10, 43, 0));
+    assert_eq!(p.get_chalakim(), 10);
 
     //check error
     let hebrew_year = HebrewYear::new(5780).unwrap();
@@ -470,9 +727,12 @@ fn test_get_molad() {
 /// assert_eq!(hebrew_date, HebrewDate::from_ymd(5779,HebrewMonth::Tishrei,NonZeroI8::new(2).unwrap())?);
 /// # Ok::<(),ConversionError>(())
 /// ```
+/// To use a different time zone, or to start the day at sunset, use `HebrewDate::from_datetime`.
+///
 /// # Error Values:
-/// * YearTooSmall - This algorithm won't work if the year is before year 4.
+/// * YearTooSmall - The date is before the year 1.
 ///
+#[cfg(feature = "chrono")]
 impl TryFrom<DateTime<Utc>> for HebrewDate {
     type Error = ConversionError;
     fn try_from(original_day: DateTime<Utc>) -> Result<HebrewDate, ConversionError> {
@@ -537,6 +797,7 @@ impl TryFrom<DateTime<Utc>> for HebrewDate {
 ///  So there's a last little bit:
 ///
 /// 14. Cheshvan and Kislev are variable length months – some years both are full, some years both are empty, and some years Cheshvan is full and Kislev is empty - depending on the day Rosh Hashana starts (and the day _the next Rosh Hashana starts_) and how many days are in the year.
+#[cfg(feature = "chrono")]
 impl From<HebrewDate> for DateTime<Utc> {
     fn from(h: HebrewDate) -> Self {
         h.to_gregorian()
@@ -544,6 +805,79 @@ impl From<HebrewDate> for DateTime<Utc> {
 }
 
 mod test {
+    #[test]
+    fn months_cover_year() {
+        use super::*;
+        for i in 5700..5800 {
+            let year = HebrewYear::new(i).unwrap();
+            let mut days = year.days();
+            for month in year.months() {
+                for day in month.days() {
+                    assert_eq!(days.next(), Some(day));
+                }
+                assert_eq!(
+                    month.first_day().days_until(month.last_day()),
+                    i64::from(month.days_in_month()) - 1
+                );
+            }
+            assert_eq!(days.next(), None);
+            assert_eq!(year.months().len() as u64, year.months_per_year);
+            assert_eq!(year.next().unwrap().prev().unwrap().year(), i);
+        }
+        assert_eq!(
+            HebrewYear::new(1).unwrap().prev().unwrap_err(),
+            ConversionError::YearTooSmall
+        );
+    }
+
+    #[test]
+    fn largest_year() {
+        use super::*;
+        use crate::convert::year::backend::LAST_YEAR_END;
+
+        let last_year = HebrewYear::new(LAST_YEAR).unwrap();
+        assert!(last_year
+            .months()
+            .all(|month| month.molad().day_number() < LAST_YEAR_END));
+        let last_day = last_year.days().next_back().unwrap();
+        assert_eq!(last_day.day_number(), LAST_YEAR_END - 1);
+        assert_eq!(HebrewDate::from_day_number(LAST_YEAR_END - 1), Ok(last_day));
+        assert_eq!(last_day.checked_add_days(1), None);
+        assert_eq!(
+            HebrewDate::from_day_number(LAST_YEAR_END),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(last_year.next(), Err(ConversionError::YearTooLarge));
+        for year in &[LAST_YEAR + 1, 1_000_000_000_000, u64::MAX] {
+            assert_eq!(HebrewYear::new(*year), Err(ConversionError::YearTooLarge));
+        }
+        assert_eq!(
+            HebrewDate::from_day_number(i64::MAX),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(
+            HebrewDate::from_rata_die(i64::MIN),
+            Err(ConversionError::YearTooSmall)
+        );
+        assert_eq!(
+            HebrewDate::from_jdn(i64::MIN),
+            Err(ConversionError::YearTooSmall)
+        );
+        let policy = ArithmeticPolicy::default();
+        assert_eq!(
+            last_day.add_months(1, policy),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(
+            last_day.add_months(i64::MAX, policy),
+            Err(ConversionError::YearTooLarge)
+        );
+        assert_eq!(
+            last_day.add_years(i64::MAX, policy),
+            Err(ConversionError::YearTooLarge)
+        );
+    }
+
     #[test]
     fn make_new_year() {
         use super::*;
@@ -554,6 +888,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn check_year_type() {
         use super::*;
@@ -1,10 +1,9 @@
 use crate::prelude::Day;
-use chrono::prelude::*;
 /// The amount of Chalakim in an hour.
-pub(crate) const CHALAKIM_PER_HOUR: u64 = 1080;
+pub(crate) const CHALAKIM_PER_HOUR: i64 = 1080;
 /// The amount of Chalakim between two Molads.
 // See https://www.chabad.org/library/article_cdo/aid/947923/jewish/Kiddush-HaChodesh-Chapter-Six.htm#bartnoteRef8a947923
-pub(crate) const CHALAKIM_BETWEEN_MOLAD: u64 =
+pub(crate) const CHALAKIM_BETWEEN_MOLAD: i64 =
     29 * 24 * CHALAKIM_PER_HOUR + 12 * CHALAKIM_PER_HOUR + 793;
 
 //An array documenting which years are leap years. The Hebrew calendar has a 19 year cycle of leap
@@ -14,22 +13,35 @@ const LEAP_YEARS: [bool; 19] = [
     false, false, true, false, true,
 ];
 
-// There are three starting dates. Right now, we don't work with negative Gregorian dates, so the
-// Epoch period is the first year of the first 19 year cycle after year 0.
+// There are three starting dates. The Epoch period is the first year of the first 19 year cycle
+// after year 0. Days and Chalakim before the Epoch are negative.
 //
 // 1. Epoch - this is the first day, is on 6:00 PM Shabbos (Saturay) afternoon.
 // 2. FIRST_MOLAD - the amount of Chalakim from Epoch to the first Molad -(Tishrei 3673). It was on Monday, September 23rd at 12:16:6 Chalakim
 // 3. FIRST_YEAR: Self described - this is the first Hebrew calendar since the epoch.
-// 4. FIRST_RH: The first Rosh Hashana since the Epoch.
-pub(crate) const FIRST_MOLAD: u64 = 24 * 1080 + 18 * 1080 + (16 * 1080 / 60) + 6;
+pub(crate) const FIRST_MOLAD: i64 = 24 * 1080 + 18 * 1080 + (16 * 1080 / 60) + 6;
 pub(crate) const FIRST_YEAR: u64 = 3763;
-lazy_static! {
-    pub(crate) static ref FIRST_RH: chrono::DateTime<Utc> = Utc.ymd(2, 9, 23).and_hms(18, 0, 0);
-    pub(crate) static ref EPOCH: chrono::DateTime<Utc> = Utc.ymd(2, 9, 21).and_hms(18, 0, 0);
-}
+// The amount of days between the Epoch and Rosh Hashana of the year 1 (negative, as it's before the
+// Epoch).
+pub(crate) const YEAR_1_RH: i64 = get_rosh_hashana(1).0;
+// The last year which can be built. The Molad of Tishrei of the year after it is the last one
+// which (along with a few days to spare for postponing Rosh Hashana) fits in an i64 of Chalakim.
+pub(crate) const LAST_YEAR: u64 = year_of_month(
+    ((i64::MAX - FIRST_MOLAD) / CHALAKIM_BETWEEN_MOLAD) as u64 + months_before_year(FIRST_YEAR),
+) - 2;
+// The amount of days between the Epoch and the day after the last day of LAST_YEAR.
+pub(crate) const LAST_YEAR_END: i64 = get_rosh_hashana(LAST_YEAR + 1).0;
+// The Rata Die of the civil day on which the daytime of the first day since the Epoch fell out
+// (the 22nd of September, 2 CE). Rata Die 1 is the 1st of January, 1 CE (proleptic Gregorian).
+pub(crate) const EPOCH_RATA_DIE: i64 = 630;
+// The Julian Day Number of a day is its Rata Die plus this.
+pub(crate) const JDN_OF_RATA_DIE_0: i64 = 1_721_425;
+// The Unix timestamp of the Epoch (6:00 PM, the 21st of September, 2 CE).
+#[cfg(feature = "chrono")]
+pub(crate) const EPOCH_TIMESTAMP: i64 = -62_081_272_800;
 // Return the correct schedule for they year. There can be only six possible amount of days, so
 // short of a bug on my part, this should never panic.
-pub(crate) fn return_year_sched(days: u64) -> usize {
+pub(crate) const fn return_year_sched(days: u64) -> usize {
     match days {
         353 => 0,
         354 => 1,
@@ -38,7 +50,7 @@ pub(crate) fn return_year_sched(days: u64) -> usize {
         383 => 3,
         384 => 4,
         385 => 5,
-        _ => panic!(format!("Wrong amount of days {}", days)),
+        _ => panic!("Wrong amount of days in year"),
     }
 }
 pub(crate) const YEAR_SCHED: [[u8; 14]; 6] = [
@@ -50,25 +62,16 @@ pub(crate) const YEAR_SCHED: [[u8; 14]; 6] = [
     [30, 30, 30, 29, 30, 0, 30, 29, 30, 29, 30, 29, 30, 29],
 ];
 
-//This calculates the amount of Chalakim per 19 year cycle.
-pub(crate) const AMNT_CHALAKIM_PER_CYCLE: u64 =
-    7 * 13 * CHALAKIM_BETWEEN_MOLAD + 12 * 12 * CHALAKIM_BETWEEN_MOLAD;
-
-fn get_molad_for_year(year: u64) -> u64 {
-    let amnt_of_cycles = (year - FIRST_YEAR) / 19;
-
-    let mut amnt_chalakim = AMNT_CHALAKIM_PER_CYCLE * amnt_of_cycles;
-    let cur_year_in_cycle = (year - FIRST_YEAR) % 19;
-    for i in 0..cur_year_in_cycle {
-        amnt_chalakim += if LEAP_YEARS[i as usize] { 13 } else { 12 } * CHALAKIM_BETWEEN_MOLAD;
-    }
-
-    amnt_chalakim
+//The amount of Chalakim between the first Molad and the Molad of Tishrei of the given year.
+const fn get_molad_for_year(year: u64) -> i64 {
+    (months_before_year(year) as i64 - months_before_year(FIRST_YEAR) as i64)
+        * CHALAKIM_BETWEEN_MOLAD
 }
 
-//Does short calculation if this year is a leap year.
-pub(crate) fn months_per_year(year: u64) -> u64 {
-    let year_in_cycle = ((year - FIRST_YEAR) % 19) as usize;
+//Does short calculation if this year is a leap year. Year 0 is treated as the last year of its
+//cycle, so that this works for the year before year 1.
+pub(crate) const fn months_per_year(year: u64) -> u64 {
+    let year_in_cycle = ((year + 18) % 19) as usize;
     if LEAP_YEARS[year_in_cycle] {
         13
     } else {
@@ -76,14 +79,41 @@ pub(crate) fn months_per_year(year: u64) -> u64 {
     }
 }
 
+//Rosh Hashana of the years 5600 until 6000 (1839 - 2240) is calculated at compile time, so that
+//getting a HebrewYear in those years (along with the Rosh Hashana of the year after it) is a
+//lookup.
+const FIRST_CACHED_YEAR: u64 = 5600;
+const LAST_CACHED_YEAR: u64 = 6000;
+const CACHED_YEARS: usize = (LAST_CACHED_YEAR - FIRST_CACHED_YEAR + 1) as usize;
+const ROSH_HASHANAS: &[(i64, Day, i64); CACHED_YEARS] = &rosh_hashana_table();
+
+const fn rosh_hashana_table() -> [(i64, Day, i64); CACHED_YEARS] {
+    let mut table = [(0, Day::Sunday, 0); CACHED_YEARS];
+    let mut i = 0;
+    while i < CACHED_YEARS {
+        table[i] = get_rosh_hashana(FIRST_CACHED_YEAR + i as u64);
+        i += 1;
+    }
+    table
+}
+
+//Like get_rosh_hashana, but looks the year up in the table if it's there.
+pub(crate) const fn rosh_hashana(year: u64) -> (i64, Day, i64) {
+    if year >= FIRST_CACHED_YEAR && year <= LAST_CACHED_YEAR {
+        ROSH_HASHANAS[(year - FIRST_CACHED_YEAR) as usize]
+    } else {
+        get_rosh_hashana(year)
+    }
+}
+
 //Calculate how many Chalakim between Epoch and Rosh Hashana, and which day of the week does it
 //fall out on.
-pub(crate) fn get_rosh_hashana(year: u64) -> (u64, Day, u64) {
+pub(crate) const fn get_rosh_hashana(year: u64) -> (i64, Day, i64) {
     let amnt_chalakim_since_first_molad = get_molad_for_year(year);
     let amnt_chalakim_since_epoch = amnt_chalakim_since_first_molad + FIRST_MOLAD;
 
-    let mut amnt_days = amnt_chalakim_since_epoch / (CHALAKIM_PER_HOUR * 24);
-    let amnt_chalakim = amnt_chalakim_since_epoch % (CHALAKIM_PER_HOUR * 24);
+    let mut amnt_days = amnt_chalakim_since_epoch.div_euclid(CHALAKIM_PER_HOUR * 24);
+    let amnt_chalakim = amnt_chalakim_since_epoch.rem_euclid(CHALAKIM_PER_HOUR * 24);
     let mut reg_postpone = false;
     //If the Molad is in the afternoon, postpone Rosh Hashana by a day
     if amnt_chalakim > 18 * CHALAKIM_PER_HOUR {
@@ -91,11 +121,10 @@ pub(crate) fn get_rosh_hashana(year: u64) -> (u64, Day, u64) {
         reg_postpone = true;
     }
 
-    //This shouldn't panic, as there are seven options in Day (seven days in week).
-    let mut dow = Day::from((amnt_days) % 7);
+    let mut dow = day_of_week(amnt_days);
     // Lo Adu Rosh
 
-    if dow == Day::Sunday || dow == Day::Wednesday || dow == Day::Friday {
+    if matches!(dow, Day::Sunday | Day::Wednesday | Day::Friday) {
         amnt_days += 1;
         reg_postpone = true;
     }
@@ -103,7 +132,7 @@ pub(crate) fn get_rosh_hashana(year: u64) -> (u64, Day, u64) {
     // See Hilchos Kiddush HaChodesh Halacha 4
 
     if !reg_postpone
-        && dow == Day::Tuesday
+        && matches!(dow, Day::Tuesday)
         && amnt_chalakim > 9 * CHALAKIM_PER_HOUR + 204
         && months_per_year(year) == 12
     {
@@ -112,33 +141,73 @@ pub(crate) fn get_rosh_hashana(year: u64) -> (u64, Day, u64) {
 
     if !reg_postpone
         && months_per_year(year - 1) == 13
-        && dow == Day::Monday
+        && matches!(dow, Day::Monday)
         && amnt_chalakim > 12 * CHALAKIM_PER_HOUR + 3 * CHALAKIM_PER_HOUR + 589
     {
         amnt_days += 1;
     }
 
-    //This shouldn't panic, as there are seven options in Day (seven days in week).
-    dow = Day::from((amnt_days) % 7);
+    dow = day_of_week(amnt_days);
 
     (amnt_days, dow, amnt_chalakim_since_first_molad)
 }
 
-pub(crate) fn day_of_last_rh(days_since_first_rh: u64) -> u64 {
-    let mut cur_year = (FIRST_YEAR) + 19 * days_since_first_rh / 6956;
-    if get_rosh_hashana(cur_year).0 > days_since_first_rh {
-        panic!("get_rosh_hashana(cur_year).0 < days_since_first_rh ");
+//The day of the week of the given amount of days since the Epoch (which was a Sunday). This is
+//Day::from, but it can be used in a const fn.
+pub(crate) const fn day_of_week(days_since_epoch: i64) -> Day {
+    match days_since_epoch.rem_euclid(7) {
+        0 => Day::Sunday,
+        1 => Day::Monday,
+        2 => Day::Tuesday,
+        3 => Day::Wednesday,
+        4 => Day::Thursday,
+        5 => Day::Friday,
+        _ => Day::Shabbos,
     }
-    while get_rosh_hashana(cur_year + 1).0 <= days_since_first_rh {
-        cur_year += 1;
+}
+
+//The amount of months between the Molad of Tishrei of year 1 and the Molad of Tishrei of the given
+//year. There are 235 months in every 19 year cycle, and the leap years are spread out so that this
+//can be calculated directly.
+pub(crate) const fn months_before_year(year: u64) -> u64 {
+    (235 * year - 234) / 19
+}
+
+//The inverse of months_before_year - returns the year in which the given month (counted from
+//Tishrei of year 1) falls out. This is the largest year for which months_before_year(year) <=
+//months, which is when 235 * year - 234 < 19 * (months + 1).
+pub(crate) const fn year_of_month(months: u64) -> u64 {
+    (19 * months + 252) / 235
+}
+
+//Returns the year in which the given day falls out. The day must not be before Rosh Hashana of
+//year 1.
+pub(crate) const fn day_of_last_rh(days_since_epoch: i64) -> u64 {
+    //Find the year of the last Molad Tishrei before the end of the day. Rosh Hashana is never
+    //before the day of its Molad, and is at most two days after it, so the day is either in that
+    //year or in the year before it.
+    let chalakim_since_first_molad = (days_since_epoch + 1) * 24 * CHALAKIM_PER_HOUR - FIRST_MOLAD;
+    let months_since_first_molad = chalakim_since_first_molad.div_euclid(CHALAKIM_BETWEEN_MOLAD);
+    let months = months_before_year(FIRST_YEAR) as i64 + months_since_first_molad;
+    let months = if months < 0 { 0 } else { months as u64 };
+    let year = year_of_month(months);
+    if year > 1 && rosh_hashana(year).0 > days_since_epoch {
+        year - 1
+    } else {
+        year
     }
-    cur_year
 }
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "chrono")]
     use crate::convert::HebrewDate;
+    #[cfg(feature = "chrono")]
     use crate::prelude::*;
+    #[cfg(feature = "chrono")]
+    use chrono::prelude::*;
+    #[cfg(feature = "chrono")]
     use chrono::Duration;
+    #[cfg(feature = "chrono")]
     use std::num::NonZeroI8;
 
     use super::*;
@@ -157,15 +226,56 @@ mod tests {
         use rayon;
         use rayon::prelude::*;
 
-        ((FIRST_YEAR + 1)..1000000)
+        (1..1_000_000u64)
             .into_par_iter()
             .map(|i| {
                 let amnt_days_between_rh_and_epoch = get_rosh_hashana(i).0;
                 let amnt_days_in_year = get_rosh_hashana(i + 1).0 - amnt_days_between_rh_and_epoch;
-                return_year_sched(amnt_days_in_year);
+                return_year_sched(amnt_days_in_year as u64);
             })
             .count();
     }
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn epoch_constants_are_right() {
+        use chrono::prelude::*;
+        assert_eq!(
+            Utc.ymd(2, 9, 21).and_hms(18, 0, 0).timestamp(),
+            EPOCH_TIMESTAMP
+        );
+        assert_eq!(
+            NaiveDate::from_ymd(2, 9, 22).num_days_from_ce() as i64,
+            EPOCH_RATA_DIE
+        );
+    }
+    #[test]
+    fn cached_rosh_hashanas_match() {
+        for year in FIRST_CACHED_YEAR - 10..=LAST_CACHED_YEAR + 10 {
+            assert_eq!(rosh_hashana(year), get_rosh_hashana(year));
+        }
+    }
+    #[test]
+    fn day_of_last_rh_finds_year() {
+        for year in 1..20000 {
+            let rh = get_rosh_hashana(year).0;
+            assert_eq!(day_of_last_rh(rh), year);
+            assert_eq!(day_of_last_rh(get_rosh_hashana(year + 1).0 - 1), year);
+        }
+    }
+    #[test]
+    fn months_before_year_matches_leap_years() {
+        for year in 1..10000 {
+            assert_eq!(
+                months_before_year(year + 1) - months_before_year(year),
+                months_per_year(year)
+            );
+            assert_eq!(year_of_month(months_before_year(year)), year);
+            assert_eq!(year_of_month(months_before_year(year) + 1), year);
+            assert_eq!(year_of_month(months_before_year(year + 1) - 1), year);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn compare_hebrew_day_elul_sanity_check() {
         let mut orig_date = Utc.ymd(1901, 8, 15).and_hms(18, 0, 0);
@@ -179,6 +289,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn compare_hebrew_day_tishrei_sanity_check() {
         let mut orig_date = Utc.ymd(1900, 9, 23).and_hms(18, 0, 0);
@@ -192,6 +303,7 @@ mod tests {
             orig_date = orig_date + Duration::days(1);
         }
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn compare_hebrew_day_adar1_sanity_check() {
         let mut orig_date = Utc.ymd(1900, 1, 30).and_hms(18, 0, 0);
@@ -205,20 +317,23 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_rh_against_working_list() {
         test_against_working_list("RoshHashanaList", 1, HebrewMonth::Tishrei);
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_adar1_against_working_list() {
         test_against_working_list("Adar1List", 1, HebrewMonth::Adar1);
     }
 
+    #[cfg(feature = "chrono")]
     fn test_against_working_list(filename: &str, day: u8, month: HebrewMonth) {
         let file_contents = std::fs::read_to_string(format!("./testing/{}", filename)).unwrap();
         file_contents
             .split("\n")
-            .filter(|x| *x != "")
+            .filter(|x| !x.is_empty())
             .for_each(|x| {
                 let res = x.split(" ").collect::<Vec<&str>>();
                 if res.len() != 1 {
@@ -0,0 +1,252 @@
+//! Writing dates in Hebrew letters.
+//!
+//! Hebrew numbers are written with letters (gematria), so the 10th of Tishrei 5779 is written as
+//! "י׳ תשרי תשע״ט". A number with one letter is followed by a geresh (׳), and a number with more
+//! than one letter has gershayim (״) before its last letter. 15 and 16 are written as ט״ו and ט״ז,
+//! and not as י״ה and י״ו, as those are names of God.
+//!
+//! # Examples:
+//!
+//! ```
+//! use std::num::NonZeroI8;
+//!
+//! use heca_lib::format::{gematria, month_name};
+//! use heca_lib::prelude::*;
+//! use heca_lib::HebrewDate;
+//!
+//! assert_eq!(gematria(15, false), "ט״ו");
+//! assert_eq!(gematria(5779, false), "תשע״ט");
+//! assert_eq!(gematria(5779, true), "ה׳תשע״ט");
+//! assert_eq!(month_name(HebrewMonth::Adar1), "אדר א׳");
+//!
+//! let date = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+//! assert_eq!(date.format_hebrew(), "י׳ תשרי תשע״ט");
+//! # Ok::<(),ConversionError>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::convert::HebrewDate;
+use crate::prelude::{HebrewMonth, Locale, Localized};
+
+pub(crate) const GERESH: char = '\u{05F3}';
+pub(crate) const GERSHAYIM: char = '\u{05F4}';
+
+pub(crate) const ONES: [char; 9] = ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט'];
+pub(crate) const TENS: [char; 9] = ['י', 'כ', 'ל', 'מ', 'נ', 'ס', 'ע', 'פ', 'צ'];
+pub(crate) const HUNDREDS: [char; 4] = ['ק', 'ר', 'ש', 'ת'];
+
+/// Returns a number written in Hebrew letters.
+///
+/// If `thousands` is true, the thousands are written before the rest of the number, so that 5779 is
+/// ה׳תשע״ט. Otherwise they're left out (as is usual when writing the year), and 5779 is תשע״ט. A
+/// round thousand (such as 5000) always keeps its thousands, as there would be nothing left
+/// otherwise. 0 is an empty string.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::format::gematria;
+///
+/// assert_eq!(gematria(1, false), "א׳");
+/// assert_eq!(gematria(16, false), "ט״ז");
+/// assert_eq!(gematria(613, false), "תרי״ג");
+/// assert_eq!(gematria(5000, false), "ה׳");
+/// assert_eq!(gematria(5780, true), "ה׳תש״פ");
+/// ```
+pub fn gematria(number: u64, thousands: bool) -> String {
+    let mut result = String::new();
+    let rest = number % 1000;
+    if number >= 1000 && (thousands || rest == 0) {
+        push_letters(&mut result, number / 1000);
+        result.push(GERESH);
+    }
+    let start = result.chars().count();
+    push_letters(&mut result, rest);
+    let letters = result.chars().count() - start;
+    if letters == 1 {
+        result.push(GERESH);
+    } else if letters > 1 {
+        //Put the gershayim before the last letter.
+        let last = result.pop().unwrap();
+        result.push(GERSHAYIM);
+        result.push(last);
+    }
+    result
+}
+
+//Writes the letters of a number, without any punctuation.
+fn push_letters(result: &mut String, number: u64) {
+    let mut hundreds = number / 100;
+    while hundreds > 4 {
+        result.push('ת');
+        hundreds -= 4;
+    }
+    if hundreds > 0 {
+        result.push(HUNDREDS[hundreds as usize - 1]);
+    }
+    match number % 100 {
+        15 => result.push_str("טו"),
+        16 => result.push_str("טז"),
+        rest => {
+            if rest >= 10 {
+                result.push(TENS[(rest / 10) as usize - 1]);
+            }
+            if rest % 10 > 0 {
+                result.push(ONES[(rest % 10) as usize - 1]);
+            }
+        }
+    }
+}
+
+/// Returns the Hebrew name of a month.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::format::month_name;
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!(month_name(HebrewMonth::Cheshvan), "חשון");
+/// assert_eq!(month_name(HebrewMonth::Adar2), "אדר ב׳");
+/// ```
+pub fn month_name(month: HebrewMonth) -> &'static str {
+    month.localized(Locale::Hebrew)
+}
+
+impl HebrewDate {
+    /// Returns the date written in Hebrew, with the day and the year in Hebrew letters (and
+    /// without the thousands of the year).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::num::NonZeroI8;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewDate;
+    ///
+    /// let date = HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(15).unwrap())?;
+    /// assert_eq!(date.format_hebrew(), "ט״ו אדר ב׳ תשע״ט");
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn format_hebrew(&self) -> String {
+        let mut result = gematria(self.day().get() as u64, false);
+        result.push(' ');
+        result.push_str(month_name(self.month()));
+        result.push(' ');
+        result.push_str(&gematria(self.year(), false));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::HebrewYear;
+
+    //Reads back a number written by gematria.
+    fn value_of(s: &str) -> u64 {
+        let mut thousands = 0;
+        let mut total = 0;
+        let len = s.chars().count();
+        for (i, c) in s.chars().enumerate() {
+            let value = if let Some(i) = ONES.iter().position(|x| *x == c) {
+                i as u64 + 1
+            } else if let Some(i) = TENS.iter().position(|x| *x == c) {
+                (i as u64 + 1) * 10
+            } else if let Some(i) = HUNDREDS.iter().position(|x| *x == c) {
+                (i as u64 + 1) * 100
+            } else {
+                assert!(c == GERESH || c == GERSHAYIM, "Unexpected {} in {}", c, s);
+                if c == GERESH && i + 1 != len {
+                    thousands = total;
+                    total = 0;
+                }
+                continue;
+            };
+            total += value;
+        }
+        thousands * 1000 + total
+    }
+
+    fn is_well_formed(s: &str) {
+        let chars = s.chars().collect::<Vec<_>>();
+        let rest = match chars.iter().position(|x| *x == GERESH) {
+            Some(i) if i + 1 != chars.len() => &chars[i + 1..],
+            _ => &chars[..],
+        };
+        if rest.len() == 2 {
+            assert_eq!(rest[1], GERESH, "{}", s);
+        } else {
+            assert_eq!(rest[rest.len() - 2], GERSHAYIM, "{}", s);
+            assert_eq!(rest.iter().filter(|x| **x == GERSHAYIM).count(), 1, "{}", s);
+        }
+        let letters = chars
+            .iter()
+            .filter(|x| **x != GERESH && **x != GERSHAYIM)
+            .collect::<String>();
+        assert!(!letters.contains("יה") && !letters.contains("יו"), "{}", s);
+    }
+
+    #[test]
+    fn gematria_round_trips() {
+        assert_eq!(gematria(0, false), "");
+        assert_eq!(gematria(0, true), "");
+        for number in 1..10000 {
+            let with_thousands = gematria(number, true);
+            is_well_formed(&with_thousands);
+            //A round thousand is written like the number of thousands.
+            let expected = if number % 1000 == 0 {
+                number / 1000
+            } else {
+                number
+            };
+            assert_eq!(value_of(&with_thousands), expected, "{}", with_thousands);
+            let without_thousands = gematria(number, false);
+            is_well_formed(&without_thousands);
+            if number % 1000 == 0 {
+                assert_eq!(with_thousands, without_thousands);
+            } else {
+                assert_eq!(value_of(&without_thousands), number % 1000);
+            }
+        }
+    }
+
+    #[test]
+    fn known_numbers() {
+        assert_eq!(gematria(15, false), "ט״ו");
+        assert_eq!(gematria(115, false), "קט״ו");
+        assert_eq!(gematria(216, false), "רט״ז");
+        assert_eq!(gematria(30, false), "ל׳");
+        assert_eq!(gematria(400, false), "ת׳");
+        assert_eq!(gematria(900, false), "תת״ק");
+        assert_eq!(gematria(1000, false), "א׳");
+        assert_eq!(gematria(5779, true), "ה׳תשע״ט");
+        assert_eq!(gematria(5784, false), "תשפ״ד");
+        assert_eq!(gematria(3761, true), "ג׳תשס״א");
+    }
+
+    #[test]
+    fn every_date_formats() {
+        for year in (1..10000).step_by(7) {
+            let hebrew_year = HebrewYear::new(year).unwrap();
+            for date in hebrew_year.days() {
+                let formatted = date.format_hebrew();
+                let parts = formatted.split(' ').collect::<Vec<_>>();
+                assert_eq!(value_of(parts[0]), date.day().get() as u64);
+                assert_eq!(
+                    parts[1..parts.len() - 1].join(" "),
+                    month_name(date.month())
+                );
+                assert_eq!(
+                    value_of(parts[parts.len() - 1]),
+                    if year % 1000 == 0 {
+                        year / 1000
+                    } else {
+                        year % 1000
+                    }
+                );
+            }
+        }
+    }
+}
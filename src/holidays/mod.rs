@@ -2,7 +2,9 @@ use smallvec::*;
 
 use crate::prelude::*;
 use crate::{HebrewDate, HebrewYear};
-use std::num::NonZeroI8;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::num::NonZeroI8;
 
 #[inline]
 pub(crate) fn get_yt_list(
@@ -154,7 +156,7 @@ pub(crate) fn get_yt_list(
         });
     }
 
-    v1.into()
+    v1
 }
 
 pub(crate) fn get_chol_list(year: HebrewYear) -> SmallVec<[TorahReadingDay; 256]> {
@@ -268,7 +270,7 @@ pub(crate) fn get_chol_list(year: HebrewYear) -> SmallVec<[TorahReadingDay; 256]
             name: TorahReading::Chol(Chol::RoshChodeshElul2),
         },
     ];
-    let mut second_vector = {
+    let second_vector = {
         let mut in_vec: SmallVec<[TorahReadingDay; 256]> = SmallVec::new();
         if year.sched[1] == 30 {
             in_vec.push(TorahReadingDay {
@@ -458,7 +460,7 @@ pub(crate) fn get_chol_list(year: HebrewYear) -> SmallVec<[TorahReadingDay; 256]
 
     //17th of Tammuz is on Shabbos when the next Rosh Hashana starts on Monday (For example
     //5782/5783).
-    let mut third_vector = if year.day_of_next_rh == Day::Monday {
+    let third_vector = if year.day_of_next_rh == Day::Monday {
         vec![
             TorahReadingDay {
                 day: year
@@ -505,9 +507,9 @@ pub(crate) fn get_chol_list(year: HebrewYear) -> SmallVec<[TorahReadingDay; 256]
         }
     };
 
-    special_days.extend_from_slice(&mut second_vector);
+    special_days.extend_from_slice(&second_vector);
     special_days.push(tzom_gedalya);
-    special_days.extend_from_slice(&mut third_vector);
+    special_days.extend_from_slice(&third_vector);
 
     special_days
 }
@@ -656,7 +658,7 @@ pub(crate) fn get_special_parsha_list(year: HebrewYear) -> SmallVec<[TorahReadin
                     Day::Monday => 25,
                     Day::Thursday => 29,
                     Day::Shabbos => 27,
-                    _ => panic!(format!("Day is on {:?}, violating ADU rosh", rh_dow_next)),
+                    _ => panic!("Day is on {:?}, violating ADU rosh", rh_dow_next),
                 })
                 .unwrap(),
             )
@@ -677,7 +679,7 @@ pub(crate) fn get_special_parsha_list(year: HebrewYear) -> SmallVec<[TorahReadin
                 Day::Tuesday => 8,   //For example, 5781
                 Day::Thursday => 13, // For example, 5784
                 Day::Shabbos => 11,  //For example, 5780
-                _ => panic!(format!("Day is on {:?}, violating ADU rosh", rh_dow_next)),
+                _ => panic!("Day is on {:?}, violating ADU rosh", rh_dow_next),
             };
             year.get_hebrew_date(month, NonZeroI8::new(day).unwrap())
                 .unwrap()
@@ -697,7 +699,7 @@ pub(crate) fn get_special_parsha_list(year: HebrewYear) -> SmallVec<[TorahReadin
                 Day::Tuesday => 22,  //For example, 5781
                 Day::Thursday => 20, // For example, 5784
                 Day::Shabbos => 18,  //For example, 5780
-                _ => panic!(format!("Day is on {:?}, violating ADU rosh", rh_dow_next)),
+                _ => panic!("Day is on {:?}, violating ADU rosh", rh_dow_next),
             };
             year.get_hebrew_date(month, NonZeroI8::new(day).unwrap())
                 .unwrap()
@@ -722,7 +724,7 @@ pub(crate) fn get_special_parsha_list(year: HebrewYear) -> SmallVec<[TorahReadin
                     Day::Tuesday => 29,  //For example, 5781
                     Day::Thursday => 27, // For example, 5784
                     Day::Shabbos => 25,  //For example, 5780
-                    _ => panic!(format!("Day is on {:?}, violating ADU rosh", rh_dow_next)),
+                    _ => panic!("Day is on {:?}, violating ADU rosh", rh_dow_next),
                 };
                 year.get_hebrew_date(month, NonZeroI8::new(day).unwrap())
                     .unwrap()
@@ -738,11 +740,14 @@ pub(crate) fn get_shabbosim(
     year: HebrewYear,
     ignore_dates: &[TorahReadingDay],
 ) -> (Vec<HebrewDate>, Vec<HebrewDate>) {
-    let amnt_days_to_shabbos = Day::Shabbos as u64 - (year.day_of_rh as u64);
-    let mut cur_day = year.days_since_epoch + amnt_days_to_shabbos;
+    //Tishrei always has 30 days, so there's always a Shabbos in it.
+    let mut cur_day = year
+        .first_weekday_of(HebrewMonth::Tishrei, Day::Shabbos)
+        .unwrap()
+        .days_since_epoch();
     let mut return_regular_shabbosim: Vec<HebrewDate> = Vec::new();
     let mut return_special_shabbosim: Vec<HebrewDate> = Vec::new();
-    while cur_day < year.days_since_epoch + year.year_len {
+    while cur_day < year.days_since_epoch + year.year_len as i64 {
         let day = year.get_hebrewdate_from_days_after_rh(cur_day);
         if ignore_dates.iter().filter(|x| x.day == day).count() == 0 {
             return_regular_shabbosim.push(day);
@@ -802,7 +807,9 @@ const DEVARIM_KISAVO: [Parsha; 7] = [
 #[cfg(test)]
 mod test {
     use crate::holidays::*;
+    #[cfg(feature = "chrono")]
     use chrono::prelude::*;
+    #[cfg(feature = "chrono")]
     #[test]
     fn purim_should_never_start_on_a_friday_night() {
         for i in 3764..9999 {
@@ -813,6 +820,7 @@ mod test {
             }
         }
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn fasts_should_never_start_on_friday_night() {
         for i in 3764..9999 {
@@ -838,6 +846,7 @@ mod test {
             }
         }
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn check_shekalim_on_shabbos_mevorchim_or_rosh_chodesh() {
         use chrono::Duration;
@@ -863,6 +872,7 @@ mod test {
             }
         }
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn check_hachodesh_on_shabbos_mevorchim_or_rosh_chodesh() {
         use chrono::Duration;
@@ -883,6 +893,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn check_zachor_on_shabbos_before_purim() {
         use chrono::Duration;
@@ -908,20 +919,21 @@ mod test {
             }
         }
     }
+    #[cfg(feature = "chrono")]
     #[test]
     fn check_all_shabbosim_and_torah_readings_are_on_shabbos() {
         for i in 5764..9999 {
             assert_eq!(
                 get_shabbos_list(HebrewYear::new(i).unwrap(), Location::Chul)
                     .iter()
-                    .filter(|&x| (*x).day.to_gregorian().weekday() != Weekday::Fri)
+                    .filter(|&x| x.day.to_gregorian().weekday() != Weekday::Fri)
                     .count(),
                 0
             );
             assert_eq!(
                 get_shabbos_list(HebrewYear::new(i).unwrap(), Location::Israel)
                     .iter()
-                    .filter(|&x| (*x).day.to_gregorian().weekday() != Weekday::Fri)
+                    .filter(|&x| x.day.to_gregorian().weekday() != Weekday::Fri)
                     .count(),
                 0
             );
@@ -941,6 +953,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn get_shabbosim_fall_on_shabbos() {
         for i in 3764..9999 {
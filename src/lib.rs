@@ -7,7 +7,7 @@
 //!
 //!```toml
 //!     [dependencies]
-//!     heca-lib = "1.3"
+//!     heca-lib = "2.0"
 //!```
 //!
 //! 2. Add the following to your crate root:
@@ -31,6 +31,8 @@
 //!
 //! ```
 //!
+//! # #[cfg(feature = "chrono")]
+//! # fn main() -> Result<(), heca_lib::prelude::ConversionError> {
 //! use std::num::NonZeroI8;
 //! use std::convert::TryInto;
 //!
@@ -42,14 +44,22 @@
 //!
 //! let hebrew_date: HebrewDate = Utc.ymd(2018,9,10).and_hms(17,59,59).try_into()?;
 //! assert_eq!(hebrew_date,HebrewDate::from_ymd(5779,HebrewMonth::Tishrei,NonZeroI8::new(1).unwrap())?);
-//! # Ok::<(),ConversionError>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "chrono"))]
+//! # fn main() {}
 //!
 //!```
 //!
+//!If the date is known ahead of time, `hebrew_date!(5779, Tishrei, 1)` builds it at compile time,
+//!and refuses to compile if the date doesn't exist.
+//!
 //!You can then get back a Gregorian date from this Hebrew Date.
 //!
 //!```
 //!
+//! # #[cfg(feature = "chrono")]
+//! # fn main() -> Result<(), heca_lib::prelude::ConversionError> {
 //! use std::num::NonZeroI8;
 //! use std::convert::TryInto;
 //!
@@ -59,7 +69,10 @@
 //!
 //! let eng_day: DateTime<Utc> = HebrewDate::from_ymd(5779,HebrewMonth::Tishrei,NonZeroI8::new(10).unwrap())?.into();
 //! assert_eq!(eng_day, Utc.ymd(2018, 9,18).and_hms(18,00,00));
-//! # Ok::<(),ConversionError>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "chrono"))]
+//! # fn main() {}
 //!
 //!```
 //!
@@ -84,14 +97,70 @@
 //!
 //!# Notes:
 //!
-//!This library won't work for years before 3764 (4).
+//!This library works for every year since the year 1 (3761 BCE). Gregorian dates before 1582 are
+//!in the proleptic Gregorian calendar, as used by chrono, with the year 1 BCE being year 0. To get
+//!the Julian calendar date instead, use `HebrewDate::to_julian` or `HebrewDate::to_historical`.
+//!
+//!```
+//!
+//!# #[cfg(feature = "chrono")]
+//!# fn main() -> Result<(), heca_lib::prelude::ConversionError> {
+//!use std::num::NonZeroI8;
+//!
+//!use chrono::prelude::*;
+//!use heca_lib::HebrewDate;
+//!use heca_lib::prelude::*;
+//!
+//!// What was the English day of the Exodus?
+//!let exodus: DateTime<Utc> = HebrewDate::from_ymd(2448,HebrewMonth::Nissan,NonZeroI8::new(15).unwrap())?.into();
+//!assert_eq!(exodus, Utc.ymd(-1312, 3, 12).and_hms(18, 0, 0));
+//!# Ok(())
+//!# }
+//!# #[cfg(not(feature = "chrono"))]
+//!# fn main() {}
+//!
+//!```
+//!
+//!# Features:
+//!
+//!* `std` (on by default) - Without it, the crate is `no_std`, but still needs `alloc`. Converting
+//!  a time in any time zone (`HebrewDate::from_datetime`) needs both `std` and `chrono`.
+//!* `chrono` (on by default) - Conversions to and from chrono's types. The `zmanim` and
+//!  `candle_lighting` modules need both this and `std`.
+//!* `rayon` - Convert batches of dates on multiple threads with `BatchConverter`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+extern crate alloc;
 
 #[macro_use]
-extern crate lazy_static;
+mod macros;
+pub mod anniversary;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub mod candle_lighting;
 mod convert;
+pub mod format;
 mod holidays;
+pub mod mourning;
+mod parse;
 pub mod prelude;
+pub mod serialization;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub mod zmanim;
+#[cfg(feature = "chrono")]
+#[doc(inline)]
+pub use convert::BatchConverter;
 #[doc(inline)]
 pub use convert::HebrewDate;
 #[doc(inline)]
+pub use convert::HebrewDateRange;
+#[doc(inline)]
 pub use convert::HebrewYear;
+#[doc(inline)]
+pub use convert::HebrewYearCache;
+#[cfg(feature = "chrono")]
+#[doc(inline)]
+pub use convert::HistoricalDate;
+#[doc(inline)]
+pub use convert::JulianDate;
+#[doc(inline)]
+pub use convert::MonthInfo;
@@ -0,0 +1,75 @@
+/// Builds a HebrewDate at compile time.
+///
+/// `hebrew_date!(year, month, day)` is the same as `HebrewDate::from_ymd(year, HebrewMonth::month,
+/// NonZeroI8::new(day).unwrap()).unwrap()`, but the date is checked while compiling, so an invalid
+/// date is a compile error instead of a panic. Since it's evaluated at compile time, the year and
+/// day have to be constants.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+///
+/// use heca_lib::prelude::*;
+/// use heca_lib::{hebrew_date, HebrewDate};
+///
+/// let yom_kippur = hebrew_date!(5779, Tishrei, 10);
+/// assert_eq!(
+///     yom_kippur,
+///     HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?
+/// );
+/// # Ok::<(),ConversionError>(())
+/// ```
+///
+/// 5780 isn't a leap year, so this doesn't compile:
+///
+/// ```compile_fail
+/// use heca_lib::hebrew_date;
+///
+/// let purim_katan = hebrew_date!(5780, Adar1, 14);
+/// ```
+#[macro_export]
+macro_rules! hebrew_date {
+    ($year:expr, $month:ident, $day:expr) => {{
+        const DATE: $crate::HebrewDate = match ::core::num::NonZeroI8::new($day) {
+            Some(day) => {
+                match $crate::HebrewDate::from_ymd($year, $crate::prelude::HebrewMonth::$month, day)
+                {
+                    Ok(date) => date,
+                    Err($crate::prelude::ConversionError::IsNotLeapYear) => {
+                        panic!("hebrew_date!: Adar1 and Adar2 only exist in a leap year")
+                    }
+                    Err($crate::prelude::ConversionError::IsLeapYear) => {
+                        panic!(
+                            "hebrew_date!: Adar doesn't exist in a leap year, use Adar1 or Adar2"
+                        )
+                    }
+                    Err($crate::prelude::ConversionError::TooManyDaysInMonth(_)) => {
+                        panic!("hebrew_date!: There aren't that many days in the month")
+                    }
+                    Err(_) => panic!("hebrew_date!: The year must be at least 1"),
+                }
+            }
+            None => panic!("hebrew_date!: The day can't be 0"),
+        };
+        DATE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::HebrewDate;
+    use core::num::NonZeroI8;
+
+    #[test]
+    fn macro_matches_from_ymd() {
+        const PESACH: HebrewDate = hebrew_date!(2448, Nissan, 15);
+        assert_eq!(
+            PESACH,
+            HebrewDate::from_ymd(2448, HebrewMonth::Nissan, NonZeroI8::new(15).unwrap()).unwrap()
+        );
+        assert_eq!(hebrew_date!(5779, Adar1, 30).day().get(), 30);
+        assert_eq!(hebrew_date!(1, Tishrei, 1).year(), 1);
+    }
+}
@@ -0,0 +1,383 @@
+//! Finding when the periods of mourning end.
+//!
+//! Shiva and shloshim are counted from the day of the burial, which is the first day of both. A
+//! Yom Tov (Rosh Hashana, Yom Kippur, Pesach, Shavuos or Sukkos) that starts during shiva cancels
+//! the rest of shiva, and one that starts during shloshim after shiva cancels the rest of
+//! shloshim. A burial during Yom Tov or Chol HaMoed doesn't start shiva until after Yom Tov, but
+//! in the Diaspora, the second day of Yom Tov at the end of the festival already counts as a day
+//! of shiva.
+//!
+//! When Pesach, Shavuos or Sukkos cancels shiva, it counts as seven days of shloshim, and so do the
+//! days of the festival (Shavuos counts as seven days, and so does Shmini Atzeres). When Rosh
+//! Hashana cancels shiva, Yom Kippur cancels shloshim, and when Yom Kippur cancels shiva, Sukkos
+//! cancels shloshim.
+//!
+//! The twelve months and kaddish (eleven months) are also counted here from the burial, and always
+//! last twelve or eleven months, even in a leap year.
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use smallvec::SmallVec;
+
+use crate::convert::{HebrewDate, HebrewYear};
+use crate::holidays::get_yt_list;
+use crate::prelude::*;
+
+/// A day in a period of mourning, both as a Hebrew and as a Gregorian date.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MourningDay {
+    hebrew: HebrewDate,
+    #[cfg(feature = "chrono")]
+    gregorian: NaiveDate,
+}
+
+impl MourningDay {
+    /// Returns the Hebrew date of this day.
+    pub fn hebrew(&self) -> HebrewDate {
+        self.hebrew
+    }
+
+    /// Returns the civil date of the daytime of this day.
+    #[cfg(feature = "chrono")]
+    pub fn gregorian(&self) -> NaiveDate {
+        self.gregorian
+    }
+}
+
+/// # Error Values:
+/// * YearTooLarge - The date is after the last date a NaiveDate can hold (in the year 262143).
+impl TryFrom<HebrewDate> for MourningDay {
+    type Error = ConversionError;
+    fn try_from(hebrew: HebrewDate) -> Result<MourningDay, ConversionError> {
+        Ok(MourningDay {
+            hebrew,
+            #[cfg(feature = "chrono")]
+            gregorian: NaiveDate::try_from(hebrew)?,
+        })
+    }
+}
+
+/// When each period of mourning ends. Every day here is the last day of its period (in practice,
+/// shiva and shloshim end in the morning of that day, and a period cancelled by Yom Tov ends before
+/// Yom Tov starts).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MourningPeriods {
+    /// The first day counted as a day of shiva. This is the day of the burial, unless the burial
+    /// was on Yom Tov or Chol HaMoed.
+    pub shiva_start: MourningDay,
+    pub shiva_end: MourningDay,
+    pub shloshim_end: MourningDay,
+    pub kaddish_end: MourningDay,
+    pub twelve_months_end: MourningDay,
+    /// The first day of the Yom Tov which cancelled the rest of shiva, if there was one.
+    pub shiva_cancelled_by: Option<YomTov>,
+    /// The first day of the Yom Tov which cancelled the rest of shloshim, if there was one.
+    pub shloshim_cancelled_by: Option<YomTov>,
+}
+
+//The first day of the festival which a day of Yom Tov is part of.
+fn festival_of(yom_tov: YomTov) -> YomTov {
+    match yom_tov {
+        YomTov::RoshHashanah1 | YomTov::RoshHashanah2 => YomTov::RoshHashanah1,
+        YomTov::YomKippur => YomTov::YomKippur,
+        YomTov::Sukkos1
+        | YomTov::Sukkos2
+        | YomTov::Sukkos3
+        | YomTov::Sukkos4
+        | YomTov::Sukkos5
+        | YomTov::Sukkos6
+        | YomTov::Sukkos7
+        | YomTov::ShminiAtzeres
+        | YomTov::SimchasTorah => YomTov::Sukkos1,
+        YomTov::Pesach1
+        | YomTov::Pesach2
+        | YomTov::Pesach3
+        | YomTov::Pesach4
+        | YomTov::Pesach5
+        | YomTov::Pesach6
+        | YomTov::Pesach7
+        | YomTov::Pesach8 => YomTov::Pesach1,
+        YomTov::Shavuos1 | YomTov::Shavuos2 => YomTov::Shavuos1,
+    }
+}
+
+/// Returns when shiva, shloshim, kaddish and the twelve months of mourning end, for a burial on a
+/// given day.
+///
+/// # Error Values:
+/// * YearTooLarge - One of the days is after the last date a NaiveDate can hold (in the year
+///   262143).
+///
+/// # Examples:
+///
+/// ```
+/// # #[cfg(feature = "chrono")]
+/// use chrono::NaiveDate;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::mourning::mourning_periods;
+/// use heca_lib::prelude::*;
+///
+/// let periods = mourning_periods(hebrew_date!(5780, Cheshvan, 10), Location::Chul)?;
+/// assert_eq!(periods.shiva_end.hebrew(), hebrew_date!(5780, Cheshvan, 16));
+/// # #[cfg(feature = "chrono")]
+/// assert_eq!(periods.shiva_end.gregorian(), NaiveDate::from_ymd(2019, 11, 14));
+/// assert_eq!(periods.shloshim_end.hebrew(), hebrew_date!(5780, Kislev, 9));
+/// assert_eq!(periods.kaddish_end.hebrew(), hebrew_date!(5781, Tishrei, 9));
+/// assert_eq!(periods.twelve_months_end.hebrew(), hebrew_date!(5781, Cheshvan, 9));
+///
+/// // Pesach cancels shiva, and shloshim ends on the 7th of Iyar.
+/// let periods = mourning_periods(hebrew_date!(5780, Nissan, 12), Location::Israel)?;
+/// assert_eq!(periods.shiva_cancelled_by, Some(YomTov::Pesach1));
+/// assert_eq!(periods.shiva_end.hebrew(), hebrew_date!(5780, Nissan, 14));
+/// assert_eq!(periods.shloshim_end.hebrew(), hebrew_date!(5780, Iyar, 7));
+/// # Ok::<(),ConversionError>(())
+/// ```
+pub fn mourning_periods(
+    burial: HebrewDate,
+    location: Location,
+) -> Result<MourningPeriods, ConversionError> {
+    //Shiva and shloshim can't last past the next year.
+    let mut yom_tov_days: SmallVec<[(HebrewDate, YomTov); 64]> = SmallVec::new();
+    for year in burial.year()..=burial.year() + 1 {
+        //A year after a valid year always exists.
+        let year = HebrewYear::new(year).unwrap();
+        for day in get_yt_list(year, location).iter() {
+            if let TorahReading::YomTov(yom_tov) = day.name() {
+                yom_tov_days.push((day.day(), yom_tov));
+            }
+        }
+    }
+    let add_days = |date: HebrewDate, days: i64| date.checked_add_days(days).unwrap();
+
+    //A burial on Yom Tov or Chol HaMoed starts shiva after the festival.
+    let mut shiva_start = burial;
+    if let Some((_, yom_tov)) = yom_tov_days.iter().find(|(day, _)| *day == burial) {
+        let festival = festival_of(*yom_tov);
+        let (last_day, last_yom_tov) = *yom_tov_days
+            .iter()
+            .rfind(|(day, x)| day.year() == burial.year() && festival_of(*x) == festival)
+            .unwrap();
+        let second_day = matches!(
+            last_yom_tov,
+            YomTov::SimchasTorah | YomTov::Pesach8 | YomTov::Shavuos2
+        );
+        shiva_start = if second_day {
+            last_day
+        } else {
+            add_days(last_day, 1)
+        };
+    }
+    let next_festival = |after: HebrewDate| {
+        yom_tov_days
+            .iter()
+            .find(|(day, yom_tov)| *day > after && festival_of(*yom_tov) == *yom_tov)
+            .copied()
+    };
+
+    let mut shiva_end = add_days(shiva_start, 6);
+    let mut shloshim_end = add_days(burial, 29);
+    let mut shiva_cancelled_by = None;
+    let mut shloshim_cancelled_by = None;
+    match next_festival(shiva_start) {
+        Some((first_day, festival)) if first_day <= shiva_end => {
+            shiva_end = add_days(first_day, -1);
+            shiva_cancelled_by = Some(festival);
+            match festival {
+                YomTov::RoshHashanah1 | YomTov::YomKippur => {
+                    let (first_day, festival) = next_festival(first_day).unwrap();
+                    shloshim_end = add_days(first_day, -1);
+                    shloshim_cancelled_by = Some(festival);
+                }
+                //Shiva and the seven days of Sukkos and of Shmini Atzeres leave nine days after
+                //Shmini Atzeres.
+                YomTov::Sukkos1 => shloshim_end = add_days(first_day, 16),
+                //Shavuos counts as seven days, so together with shiva, sixteen days are left
+                //after its first day.
+                YomTov::Shavuos1 => shloshim_end = add_days(first_day, 16),
+                //Shiva counts as seven days, and every day from the first day of Pesach on is
+                //counted, so the 23rd day from it is the last.
+                _ => shloshim_end = add_days(first_day, 22),
+            }
+        }
+        Some((first_day, festival)) if first_day <= shloshim_end => {
+            shloshim_end = add_days(first_day, -1);
+            shloshim_cancelled_by = Some(festival);
+        }
+        _ => {}
+    }
+
+    //A burial on the 30th of a month is counted to the 30th, even if the last month only has 29
+    //days, so carry it over into the next month before going back a day. Moving forward can't fail.
+    let policy = ArithmeticPolicy {
+        day_overflow: DayOverflow::Carry,
+        ..ArithmeticPolicy::default()
+    };
+    let months_end = |months| add_days(burial.add_months(months, policy).unwrap(), -1);
+    Ok(MourningPeriods {
+        shiva_start: MourningDay::try_from(shiva_start)?,
+        shiva_end: MourningDay::try_from(shiva_end)?,
+        shloshim_end: MourningDay::try_from(shloshim_end)?,
+        kaddish_end: MourningDay::try_from(months_end(11))?,
+        twelve_months_end: MourningDay::try_from(months_end(12))?,
+        shiva_cancelled_by,
+        shloshim_cancelled_by,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ends(burial: HebrewDate, location: Location) -> (HebrewDate, HebrewDate, HebrewDate) {
+        let periods = mourning_periods(burial, location).unwrap();
+        (
+            periods.shiva_start.hebrew(),
+            periods.shiva_end.hebrew(),
+            periods.shloshim_end.hebrew(),
+        )
+    }
+
+    #[test]
+    fn periods_are_in_order() {
+        for year in 5770..5790 {
+            for burial in HebrewYear::new(year).unwrap().days() {
+                for location in Location::all() {
+                    let periods = mourning_periods(burial, location).unwrap();
+                    let shiva_start = periods.shiva_start.hebrew();
+                    let shiva_end = periods.shiva_end.hebrew();
+                    let shloshim_end = periods.shloshim_end.hebrew();
+                    assert!(shiva_start >= burial);
+                    assert!((-1..7).contains(&shiva_start.days_until(shiva_end)));
+                    assert!(burial.days_until(shiva_end) >= 0);
+                    assert!(shiva_end <= shloshim_end);
+                    assert!(burial.days_until(shloshim_end) <= 29);
+                    assert!(shloshim_end < periods.kaddish_end.hebrew());
+                    assert!(periods.kaddish_end.hebrew() < periods.twelve_months_end.hebrew());
+                    #[cfg(feature = "chrono")]
+                    assert_eq!(
+                        periods.shiva_end.gregorian(),
+                        NaiveDate::try_from(shiva_end).unwrap()
+                    );
+                    if periods.shiva_cancelled_by.is_none() {
+                        assert_eq!(shiva_start.days_until(shiva_end), 6);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn yom_tov_cancels_mourning() {
+        for location in Location::all() {
+            //Rosh Hashana cancels shiva, and Yom Kippur cancels shloshim.
+            let periods = mourning_periods(hebrew_date!(5779, Elul, 27), location).unwrap();
+            assert_eq!(periods.shiva_end.hebrew(), hebrew_date!(5779, Elul, 29));
+            assert_eq!(periods.shiva_cancelled_by, Some(YomTov::RoshHashanah1));
+            assert_eq!(
+                periods.shloshim_end.hebrew(),
+                hebrew_date!(5780, Tishrei, 9)
+            );
+            assert_eq!(periods.shloshim_cancelled_by, Some(YomTov::YomKippur));
+            //Yom Kippur cancels shiva, and Sukkos cancels shloshim.
+            let periods = mourning_periods(hebrew_date!(5780, Tishrei, 5), location).unwrap();
+            assert_eq!(periods.shiva_end.hebrew(), hebrew_date!(5780, Tishrei, 9));
+            assert_eq!(periods.shiva_cancelled_by, Some(YomTov::YomKippur));
+            assert_eq!(
+                periods.shloshim_end.hebrew(),
+                hebrew_date!(5780, Tishrei, 14)
+            );
+            assert_eq!(periods.shloshim_cancelled_by, Some(YomTov::Sukkos1));
+
+            assert_eq!(
+                ends(hebrew_date!(5780, Tishrei, 12), location),
+                (
+                    hebrew_date!(5780, Tishrei, 12),
+                    hebrew_date!(5780, Tishrei, 14),
+                    hebrew_date!(5780, Cheshvan, 1)
+                )
+            );
+            assert_eq!(
+                ends(hebrew_date!(5780, Sivan, 3), location),
+                (
+                    hebrew_date!(5780, Sivan, 3),
+                    hebrew_date!(5780, Sivan, 5),
+                    hebrew_date!(5780, Sivan, 22)
+                )
+            );
+            //Shiva is over before Pesach, which cancels shloshim.
+            let periods = mourning_periods(hebrew_date!(5780, Nissan, 1), location).unwrap();
+            assert_eq!(periods.shiva_end.hebrew(), hebrew_date!(5780, Nissan, 7));
+            assert_eq!(periods.shiva_cancelled_by, None);
+            assert_eq!(
+                periods.shloshim_end.hebrew(),
+                hebrew_date!(5780, Nissan, 14)
+            );
+            assert_eq!(periods.shloshim_cancelled_by, Some(YomTov::Pesach1));
+            //A burial on Chol HaMoed starts shiva after Yom Tov.
+            assert_eq!(
+                ends(hebrew_date!(5780, Tishrei, 17), location),
+                (
+                    hebrew_date!(5780, Tishrei, 23),
+                    hebrew_date!(5780, Tishrei, 29),
+                    hebrew_date!(5780, Cheshvan, 16)
+                )
+            );
+        }
+        //In the Diaspora, the eighth day of Pesach counts as a day of shiva.
+        assert_eq!(
+            ends(hebrew_date!(5780, Nissan, 22), Location::Chul).0,
+            hebrew_date!(5780, Nissan, 22)
+        );
+        assert_eq!(
+            ends(hebrew_date!(5780, Nissan, 22), Location::Israel),
+            (
+                hebrew_date!(5780, Nissan, 22),
+                hebrew_date!(5780, Nissan, 28),
+                hebrew_date!(5780, Iyar, 21)
+            )
+        );
+    }
+
+    #[test]
+    fn months_ignore_leap_years() {
+        //5782 is a leap year, but the twelve months still end before Nissan.
+        let periods = mourning_periods(hebrew_date!(5781, Nissan, 10), Location::Chul).unwrap();
+        assert_eq!(periods.kaddish_end.hebrew(), hebrew_date!(5782, Adar1, 9));
+        assert_eq!(
+            periods.twelve_months_end.hebrew(),
+            hebrew_date!(5782, Adar2, 9)
+        );
+    }
+
+    #[test]
+    fn burials_on_the_thirtieth() {
+        //Cheshvan 5780 has 30 days, but Cheshvan 5781 only has 29.
+        let periods = mourning_periods(hebrew_date!(5780, Cheshvan, 30), Location::Chul).unwrap();
+        assert_eq!(
+            periods.kaddish_end.hebrew(),
+            hebrew_date!(5781, Tishrei, 29)
+        );
+        assert_eq!(
+            periods.twelve_months_end.hebrew(),
+            hebrew_date!(5781, Cheshvan, 29)
+        );
+        //Teves 5780 only has 29 days.
+        let periods = mourning_periods(hebrew_date!(5779, Adar1, 30), Location::Chul).unwrap();
+        assert_eq!(periods.kaddish_end.hebrew(), hebrew_date!(5780, Teves, 29));
+        assert_eq!(
+            periods.twelve_months_end.hebrew(),
+            hebrew_date!(5780, Shvat, 29)
+        );
+    }
+
+    #[test]
+    fn dates_past_chrono_are_errors() {
+        let result = mourning_periods(hebrew_date!(300000, Tishrei, 1), Location::Chul);
+        #[cfg(feature = "chrono")]
+        assert_eq!(result, Err(ConversionError::YearTooLarge));
+        #[cfg(not(feature = "chrono"))]
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,420 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::num::NonZeroI8;
+use core::str::FromStr;
+
+use crate::convert::{HebrewDate, HebrewYear};
+use crate::format::{GERESH, GERSHAYIM, HUNDREDS, ONES, TENS};
+use crate::prelude::{ConversionError, HebrewMonth};
+
+//Every spelling of the months which we accept, as they are after normalize(). This covers the Ashkenazi,
+//Sephardi and Israeli spellings, and the different ways of writing Adar 1 and Adar 2.
+const MONTH_NAMES: [(&str, HebrewMonth); 72] = [
+    ("tishrei", HebrewMonth::Tishrei),
+    ("tishri", HebrewMonth::Tishrei),
+    ("tishre", HebrewMonth::Tishrei),
+    ("תשרי", HebrewMonth::Tishrei),
+    ("cheshvan", HebrewMonth::Cheshvan),
+    ("chesvan", HebrewMonth::Cheshvan),
+    ("heshvan", HebrewMonth::Cheshvan),
+    ("hesvan", HebrewMonth::Cheshvan),
+    ("marcheshvan", HebrewMonth::Cheshvan),
+    ("marheshvan", HebrewMonth::Cheshvan),
+    ("חשון", HebrewMonth::Cheshvan),
+    ("חשוון", HebrewMonth::Cheshvan),
+    ("מרחשון", HebrewMonth::Cheshvan),
+    ("מרחשוון", HebrewMonth::Cheshvan),
+    ("kislev", HebrewMonth::Kislev),
+    ("kisleiv", HebrewMonth::Kislev),
+    ("kislaiv", HebrewMonth::Kislev),
+    ("כסלו", HebrewMonth::Kislev),
+    ("כסליו", HebrewMonth::Kislev),
+    ("teves", HebrewMonth::Teves),
+    ("tevet", HebrewMonth::Teves),
+    ("teveth", HebrewMonth::Teves),
+    ("טבת", HebrewMonth::Teves),
+    ("shvat", HebrewMonth::Shvat),
+    ("shevat", HebrewMonth::Shvat),
+    ("שבט", HebrewMonth::Shvat),
+    ("adar", HebrewMonth::Adar),
+    ("אדר", HebrewMonth::Adar),
+    ("adar1", HebrewMonth::Adar1),
+    ("adari", HebrewMonth::Adar1),
+    ("adara", HebrewMonth::Adar1),
+    ("adaraleph", HebrewMonth::Adar1),
+    ("adaralef", HebrewMonth::Adar1),
+    ("adarrishon", HebrewMonth::Adar1),
+    ("אדרא", HebrewMonth::Adar1),
+    ("אדר1", HebrewMonth::Adar1),
+    ("אדרראשון", HebrewMonth::Adar1),
+    ("adar2", HebrewMonth::Adar2),
+    ("adarii", HebrewMonth::Adar2),
+    ("adarb", HebrewMonth::Adar2),
+    ("adarbeis", HebrewMonth::Adar2),
+    ("adarbet", HebrewMonth::Adar2),
+    ("adarbeth", HebrewMonth::Adar2),
+    ("adarsheni", HebrewMonth::Adar2),
+    ("veadar", HebrewMonth::Adar2),
+    ("אדרב", HebrewMonth::Adar2),
+    ("אדר2", HebrewMonth::Adar2),
+    ("אדרשני", HebrewMonth::Adar2),
+    ("nissan", HebrewMonth::Nissan),
+    ("nisan", HebrewMonth::Nissan),
+    ("ניסן", HebrewMonth::Nissan),
+    ("iyar", HebrewMonth::Iyar),
+    ("iyyar", HebrewMonth::Iyar),
+    ("iyer", HebrewMonth::Iyar),
+    ("אייר", HebrewMonth::Iyar),
+    ("איר", HebrewMonth::Iyar),
+    ("sivan", HebrewMonth::Sivan),
+    ("sivon", HebrewMonth::Sivan),
+    ("סיון", HebrewMonth::Sivan),
+    ("סיוון", HebrewMonth::Sivan),
+    ("tammuz", HebrewMonth::Tammuz),
+    ("tamuz", HebrewMonth::Tammuz),
+    ("tamus", HebrewMonth::Tammuz),
+    ("תמוז", HebrewMonth::Tammuz),
+    ("av", HebrewMonth::Av),
+    ("ov", HebrewMonth::Av),
+    ("menachemav", HebrewMonth::Av),
+    ("אב", HebrewMonth::Av),
+    ("מנחםאב", HebrewMonth::Av),
+    ("elul", HebrewMonth::Elul),
+    ("ellul", HebrewMonth::Elul),
+    ("אלול", HebrewMonth::Elul),
+];
+
+//Lowercases the string, and removes spaces, punctuation and nikud, so that "Adar II", "adar-ii"
+//and "AdarII" are all the same.
+pub(crate) fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '\'' | '’' | '"' | '-' | '.' | '׳' | '״'))
+        .filter(|c| !('\u{0591}'..='\u{05C7}').contains(c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+pub(crate) fn month_of(s: &str) -> Option<HebrewMonth> {
+    let normalized = normalize(s);
+    MONTH_NAMES
+        .iter()
+        .find(|(name, _)| *name == normalized)
+        .map(|(_, month)| *month)
+}
+
+/// Parses the name of a month.
+///
+/// Most English spellings (Ashkenazi, Sephardi and Israeli) work, as well as the Hebrew name. Adar
+/// 1 can be written as "Adar I", "Adar 1", "Adar Aleph", "Adar Rishon" or "אדר א׳", and Adar 2 in
+/// the same way. Case, spaces and punctuation are ignored.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!("Teves".parse(), Ok(HebrewMonth::Teves));
+/// assert_eq!("tevet".parse(), Ok(HebrewMonth::Teves));
+/// assert_eq!("Adar II".parse(), Ok(HebrewMonth::Adar2));
+/// assert_eq!("חשוון".parse(), Ok(HebrewMonth::Cheshvan));
+/// assert_eq!("Tebeth".parse::<HebrewMonth>(), Err(ConversionError::ParseError));
+/// ```
+impl FromStr for HebrewMonth {
+    type Err = ConversionError;
+    fn from_str(s: &str) -> Result<HebrewMonth, ConversionError> {
+        month_of(s).ok_or(ConversionError::ParseError)
+    }
+}
+
+//Returns true for a geresh, and for the apostrophes which are often typed instead of it.
+fn is_geresh(c: char) -> bool {
+    c == GERESH || c == '\'' || c == '\u{2019}'
+}
+
+//Reads a number written in Hebrew letters. If the thousands aren't written, the year is assumed
+//to be in the sixth millennium (so תשע״ט is 5779).
+fn parse_gematria(s: &str, is_year: bool) -> Option<u64> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut thousands = None;
+    let mut total = 0;
+    for (i, c) in chars.iter().enumerate() {
+        total += if let Some(i) = ONES.iter().position(|x| x == c) {
+            i as u64 + 1
+        } else if let Some(i) = TENS.iter().position(|x| x == c) {
+            (i as u64 + 1) * 10
+        } else if let Some(i) = HUNDREDS.iter().position(|x| x == c) {
+            (i as u64 + 1) * 100
+        } else if is_geresh(*c) && i == 1 && i + 1 != chars.len() {
+            //A geresh after the first letter (as in ה׳תשע״ט) separates the thousands.
+            thousands = Some(total);
+            total = 0;
+            continue;
+        } else if is_geresh(*c) || *c == GERSHAYIM || *c == '"' {
+            continue;
+        } else {
+            return None;
+        };
+    }
+    match thousands {
+        Some(thousands) => Some(thousands * 1000 + total),
+        None if total == 0 => None,
+        None if is_year => Some(5000 + total),
+        None => Some(total),
+    }
+}
+
+fn parse_number(s: &str, is_year: bool) -> Option<u64> {
+    if s.chars().all(|c| c.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        parse_gematria(s, is_year)
+    }
+}
+
+fn parse_day(day: u64) -> Result<NonZeroI8, ConversionError> {
+    if day > i8::MAX as u64 {
+        return Err(ConversionError::ParseError);
+    }
+    NonZeroI8::new(day as i8).ok_or(ConversionError::ParseError)
+}
+
+//Parses "5779-01-10", where the months are counted from Tishrei.
+fn parse_iso(s: &str) -> Option<Result<HebrewDate, ConversionError>> {
+    let parts = s.split('-').collect::<Vec<_>>();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|x| x.is_empty() || !x.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let parse = |s: &str| s.parse::<u64>().map_err(|_| ConversionError::ParseError);
+    Some(parse(parts[0]).and_then(|year| {
+        let year = HebrewYear::new(year)?;
+        let month = parse(parts[1])?;
+        if month == 0 || month > year.months_per_year {
+            return Err(ConversionError::ParseError);
+        }
+        year.get_hebrew_date(
+            year.month_from_index(month - 1),
+            parse_day(parse(parts[2])?)?,
+        )
+    }))
+}
+
+/// Parses a date.
+///
+/// The date can either be written as year-month-day, with the months counted from Tishrei (so
+/// that "5779-01-10" is the 10th of Tishrei 5779), or with the name of the month (in English or in
+/// Hebrew) and the day and the year around it, in any order that has the day before the year. The
+/// day and the year can be written in digits or in Hebrew letters. If the thousands of a year in
+/// Hebrew letters aren't written, it's assumed to be in the sixth millennium (as in תשע״ט).
+///
+/// # Error Values:
+/// * ParseError - The string isn't a date.
+/// * Otherwise, the same as `HebrewDate::from_ymd`.
+///
+/// # Examples:
+///
+/// ```
+/// use std::num::NonZeroI8;
+/// use heca_lib::prelude::*;
+/// use heca_lib::HebrewDate;
+///
+/// let yom_kippur = HebrewDate::from_ymd(5779, HebrewMonth::Tishrei, NonZeroI8::new(10).unwrap())?;
+/// assert_eq!("10 Tishrei 5779".parse(), Ok(yom_kippur));
+/// assert_eq!("י׳ בתשרי תשע״ט".parse(), Ok(yom_kippur));
+/// assert_eq!("5779-01-10".parse(), Ok(yom_kippur));
+/// assert_eq!(
+///     "Adar II 14, 5784".parse(),
+///     HebrewDate::from_ymd(5784, HebrewMonth::Adar2, NonZeroI8::new(14).unwrap())
+/// );
+/// assert_eq!("14 Adar 5784".parse::<HebrewDate>(), Err(ConversionError::IsLeapYear));
+/// # Ok::<(),ConversionError>(())
+/// ```
+impl FromStr for HebrewDate {
+    type Err = ConversionError;
+    fn from_str(s: &str) -> Result<HebrewDate, ConversionError> {
+        let s = s.trim();
+        if let Some(result) = parse_iso(s) {
+            return result;
+        }
+        let tokens = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<_>>();
+
+        //Find the month, trying the names with two words (such as "Adar II") first, and keep the
+        //first one which leaves a day and a year around it (so that "1 Adar 1" is the first of
+        //Adar in the year 1). In Hebrew, the month can have a ב before it ("בתשרי").
+        for len in (1..=2).rev() {
+            for start in 0..tokens.len().saturating_sub(len - 1) {
+                let name = tokens[start..start + len].join(" ");
+                let month = match month_of(&name).or_else(|| {
+                    name.strip_prefix('ב')
+                        .filter(|x| len == 1 && !x.is_empty())
+                        .and_then(month_of)
+                }) {
+                    Some(month) => month,
+                    None => continue,
+                };
+                let numbers = tokens[..start]
+                    .iter()
+                    .chain(tokens[start + len..].iter())
+                    .collect::<Vec<_>>();
+                if numbers.len() != 2 {
+                    continue;
+                }
+                if let (Some(day), Some(year)) = (
+                    parse_number(numbers[0], false),
+                    parse_number(numbers[1], true),
+                ) {
+                    return HebrewDate::from_ymd(year, month, parse_day(day)?);
+                }
+            }
+        }
+        Err(ConversionError::ParseError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_months() {
+        for (name, month) in MONTH_NAMES.iter() {
+            assert_eq!(name.parse(), Ok(*month));
+        }
+        for month in HebrewMonth::all() {
+            let name = format!("{:?}", month);
+            assert_eq!(name.parse(), Ok(month));
+            assert_eq!(name.to_uppercase().parse(), Ok(month));
+            assert_eq!(crate::format::month_name(month).parse(), Ok(month));
+        }
+        assert_eq!("Adar I".parse(), Ok(HebrewMonth::Adar1));
+        assert_eq!("Adar 2".parse(), Ok(HebrewMonth::Adar2));
+        assert_eq!("Mar-Cheshvan".parse(), Ok(HebrewMonth::Cheshvan));
+        assert_eq!("Menachem Av".parse(), Ok(HebrewMonth::Av));
+        assert_eq!("אדר ב׳".parse(), Ok(HebrewMonth::Adar2));
+        assert_eq!("".parse::<HebrewMonth>(), Err(ConversionError::ParseError));
+        assert_eq!(
+            "Adar III".parse::<HebrewMonth>(),
+            Err(ConversionError::ParseError)
+        );
+        //ו׳ is the day, not the ו of ואדר.
+        assert_eq!(
+            "ו׳ אדר תש״פ".parse::<HebrewDate>(),
+            HebrewDate::from_ymd(5780, HebrewMonth::Adar, NonZeroI8::new(6).unwrap())
+        );
+        assert_eq!(
+            "ב׳ אדר תש״פ".parse::<HebrewDate>(),
+            HebrewDate::from_ymd(5780, HebrewMonth::Adar, NonZeroI8::new(2).unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_formatted_dates() {
+        for year in (1..10000).step_by(97) {
+            for date in HebrewYear::new(year).unwrap().days() {
+                let formatted = format!("{} {:?} {}", date.day(), date.month(), date.year());
+                assert_eq!(formatted.parse(), Ok(date));
+                let iso = format!(
+                    "{}-{:02}-{:02}",
+                    date.year(),
+                    date.month_number(),
+                    date.day()
+                );
+                assert_eq!(iso.parse(), Ok(date));
+                //The Hebrew format leaves out the thousands of the year.
+                if (5001..6000).contains(&year) && year % 1000 != 0 {
+                    assert_eq!(date.format_hebrew().parse(), Ok(date));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        for s in [
+            "",
+            "Tishrei",
+            "10 Tishrei",
+            "10 Tishrei 5779 12",
+            "0 Tishrei 5779",
+            "200 Tishrei 5779",
+            "10 Tishrey 5779",
+            "ten Tishrei 5779",
+            "5779-00-10",
+            "5779-14-10",
+            "5780-13-10",
+            "5779-01",
+            "5779--10",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                s.parse::<HebrewDate>(),
+                Err(ConversionError::ParseError),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            "31 Tishrei 5779".parse::<HebrewDate>(),
+            Err(ConversionError::TooManyDaysInMonth(30))
+        );
+        assert_eq!(
+            "1 Adar I 5780".parse::<HebrewDate>(),
+            Err(ConversionError::IsNotLeapYear)
+        );
+        assert_eq!(
+            "1 Tishrei 0".parse::<HebrewDate>(),
+            Err(ConversionError::YearTooSmall)
+        );
+        for s in [
+            "10 Tishrei 99999999999999",
+            "99999999999999-01-10",
+            "10 Tishrei 18446744073709551615",
+            "18446744073709551615-01-10",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                s.parse::<HebrewDate>(),
+                Err(ConversionError::YearTooLarge),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            "10 Tishrei 18446744073709551616".parse::<HebrewDate>(),
+            Err(ConversionError::ParseError)
+        );
+        assert_eq!(
+            "5779-13-29".parse::<HebrewDate>(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Elul, NonZeroI8::new(29).unwrap())
+        );
+        assert_eq!(
+            "ה׳תשע״ט טו אדר ב".parse::<HebrewDate>(),
+            Err(ConversionError::ParseError)
+        );
+        assert_eq!(
+            "ט״ו אדר ב׳ ה׳תשע״ט".parse::<HebrewDate>(),
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(15).unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_ascii_punctuation() {
+        let purim =
+            HebrewDate::from_ymd(5779, HebrewMonth::Adar2, NonZeroI8::new(15).unwrap()).unwrap();
+        assert_eq!("ט\"ו אדר ב' ה'תשע\"ט".parse(), Ok(purim));
+        assert_eq!("ט\"ו אדר ב\u{2019} ה\u{2019}תשע\"ט".parse(), Ok(purim));
+        assert_eq!("ט\"ו אדר ב' תשע\"ט".parse(), Ok(purim));
+        assert_eq!(
+            "ו' אדר ד'תשפ\"א".parse::<HebrewDate>(),
+            HebrewDate::from_ymd(4781, HebrewMonth::Adar, NonZeroI8::new(6).unwrap())
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The language (and the way of transliterating Hebrew) to get names in.
+///
+/// The default is `Ashkenazi`, which is what `Display` uses, and is also how the names are written
+/// in this library.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!(Parsha::KiSavoh.localized(Locale::Hebrew), "כי תבוא");
+/// assert_eq!(Parsha::KiSavoh.localized(Locale::HebrewNikud), "כִּי תָבוֹא");
+/// assert_eq!(Parsha::KiSavoh.localized(Locale::Ashkenazi), "Ki Savo");
+/// assert_eq!(Parsha::KiSavoh.localized(Locale::Sephardi), "Ki Tavo");
+/// assert_eq!(YomTov::Pesach1.localized(Locale::English), "Passover 1");
+/// assert_eq!(HebrewMonth::Teves.to_string(), "Teves");
+/// assert_eq!(format!("{}", TorahReading::Chol(Chol::TenTeves)), "Asara B'Teves");
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Default)]
+#[repr(u8)]
+pub enum Locale {
+    /// Hebrew, without nikud.
+    Hebrew = 0,
+    /// Hebrew, with nikud.
+    HebrewNikud = 1,
+    /// Ashkenazi transliteration (Shabbos, Sukkos).
+    #[default]
+    Ashkenazi = 2,
+    /// Sephardi or Israeli transliteration (Shabbat, Sukkot).
+    Sephardi = 3,
+    /// English names where there are common ones (Passover, Fast of Esther), and the usual English
+    /// transliteration otherwise.
+    English = 4,
+}
+
+/// Names of things in a given Locale.
+pub trait Localized {
+    /// Returns the name in the given Locale.
+    fn localized(&self, locale: Locale) -> &'static str;
+}
+
+//Implements Localized and Display for an enum, from a table of the names of every variant. The
+//names are in the same order as the variants of Locale.
+macro_rules! localized {
+    ($name:ident { $($variant:ident => $names:expr,)* }) => {
+        impl Localized for $name {
+            fn localized(&self, locale: Locale) -> &'static str {
+                let names: [&'static str; 5] = match self {
+                    $($name::$variant => $names,)*
+                };
+                names[locale as usize]
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.pad(self.localized(Locale::default()))
+            }
+        }
+    };
+}
+
+impl Localized for TorahReading {
+    fn localized(&self, locale: Locale) -> &'static str {
+        match self {
+            TorahReading::YomTov(x) => x.localized(locale),
+            TorahReading::Chol(x) => x.localized(locale),
+            TorahReading::Shabbos(x) => x.localized(locale),
+            TorahReading::SpecialParsha(x) => x.localized(locale),
+        }
+    }
+}
+
+impl fmt::Display for TorahReading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.localized(Locale::default()))
+    }
+}
+
+localized! {
+    Day {
+        Sunday => ["יום ראשון", "יוֹם רִאשׁוֹן", "Yom Rishon", "Yom Rishon", "Sunday"],
+        Monday => ["יום שני", "יוֹם שֵׁנִי", "Yom Sheni", "Yom Sheni", "Monday"],
+        Tuesday => ["יום שלישי", "יוֹם שְׁלִישִׁי", "Yom Shlishi", "Yom Shlishi", "Tuesday"],
+        Wednesday => ["יום רביעי", "יוֹם רְבִיעִי", "Yom Revi'i", "Yom Revi'i", "Wednesday"],
+        Thursday => ["יום חמישי", "יוֹם חֲמִישִׁי", "Yom Chamishi", "Yom Hamishi", "Thursday"],
+        Friday => ["יום שישי", "יוֹם שִׁשִּׁי", "Yom Shishi", "Yom Shishi", "Friday"],
+        Shabbos => ["שבת", "שַׁבָּת", "Shabbos", "Shabbat", "Saturday"],
+    }
+}
+
+localized! {
+    HebrewMonth {
+        Tishrei => ["תשרי", "תִּשְׁרֵי", "Tishrei", "Tishrei", "Tishrei"],
+        Cheshvan => ["חשון", "חֶשְׁוָן", "Cheshvan", "Heshvan", "Cheshvan"],
+        Kislev => ["כסלו", "כִּסְלֵו", "Kislev", "Kislev", "Kislev"],
+        Teves => ["טבת", "טֵבֵת", "Teves", "Tevet", "Tevet"],
+        Shvat => ["שבט", "שְׁבָט", "Shvat", "Shevat", "Shevat"],
+        Adar => ["אדר", "אֲדָר", "Adar", "Adar", "Adar"],
+        Adar1 => ["אדר א׳", "אֲדָר א׳", "Adar Rishon", "Adar Alef", "Adar I"],
+        Adar2 => ["אדר ב׳", "אֲדָר ב׳", "Adar Sheni", "Adar Bet", "Adar II"],
+        Nissan => ["ניסן", "נִיסָן", "Nissan", "Nisan", "Nisan"],
+        Iyar => ["אייר", "אִיָּר", "Iyar", "Iyar", "Iyar"],
+        Sivan => ["סיון", "סִיוָן", "Sivan", "Sivan", "Sivan"],
+        Tammuz => ["תמוז", "תַּמּוּז", "Tammuz", "Tamuz", "Tammuz"],
+        Av => ["אב", "אָב", "Av", "Av", "Av"],
+        Elul => ["אלול", "אֱלוּל", "Elul", "Elul", "Elul"],
+    }
+}
+
+localized! {
+    Location {
+        Israel => ["ארץ ישראל", "אֶרֶץ יִשְׂרָאֵל", "Eretz Yisroel", "Eretz Yisrael", "Israel"],
+        Chul => ["חוץ לארץ", "חוּץ לָאָרֶץ", "Chutz LaAretz", "Hutz LaAretz", "Diaspora"],
+    }
+}
+
+localized! {
+    TorahReadingType {
+        YomTov => ["יום טוב", "יוֹם טוֹב", "Yom Tov", "Yom Tov", "Festival"],
+        Chol => ["חול", "חוֹל", "Chol", "Hol", "Weekday"],
+        Shabbos => ["שבת", "שַׁבָּת", "Shabbos", "Shabbat", "Sabbath"],
+        SpecialParsha => ["ארבע פרשיות", "אַרְבַּע פָּרָשִׁיּוֹת", "Arba Parshiyos", "Arba Parashiyot", "Special Parsha"],
+    }
+}
+
+localized! {
+    MonthSchedule {
+        BaChaG => ["בח״ג", "בח״ג", "BaChaG", "BaHaG", "Monday, deficient, Tuesday"],
+        BaShaH => ["בש״ה", "בש״ה", "BaShaH", "BaShaH", "Monday, complete, Thursday"],
+        GaChaH => ["גח״ה", "גח״ה", "GaChaH", "GaHaH", "Tuesday, deficient, Thursday"],
+        HaKaZ => ["הכ״ז", "הכ״ז", "HaKaZ", "HaKaZ", "Thursday, regular, Saturday"],
+        HaShA => ["הש״א", "הש״א", "HaShA", "HaShA", "Thursday, complete, Sunday"],
+        ZaChA => ["זח״א", "זח״א", "ZaChA", "ZaHA", "Saturday, deficient, Sunday"],
+        ZaShaG => ["זש״ג", "זש״ג", "ZaShaG", "ZaShaG", "Saturday, complete, Tuesday"],
+        BaChaH => ["בח״ה", "בח״ה", "BaChaH", "BaHaH", "Monday, deficient, Thursday"],
+        BaShaZ => ["בש״ז", "בש״ז", "BaShaZ", "BaShaZ", "Monday, complete, Saturday"],
+        GaKaZ => ["גכ״ז", "גכ״ז", "GaKaZ", "GaKaZ", "Tuesday, regular, Saturday"],
+        HaChA => ["הח״א", "הח״א", "HaChA", "HaHA", "Thursday, deficient, Sunday"],
+        HaShaG => ["הש״ג", "הש״ג", "HaShaG", "HaShaG", "Thursday, complete, Tuesday"],
+        ZaChaG => ["זח״ג", "זח״ג", "ZaChaG", "ZaHaG", "Saturday, deficient, Tuesday"],
+        ZaShaH => ["זש״ה", "זש״ה", "ZaShaH", "ZaShaH", "Saturday, complete, Thursday"],
+    }
+}
+
+localized! {
+    YomTov {
+        RoshHashanah1 => ["ראש השנה א׳", "רֹאשׁ הַשָּׁנָה א׳", "Rosh Hashana 1", "Rosh Hashana 1", "Rosh Hashanah 1"],
+        RoshHashanah2 => ["ראש השנה ב׳", "רֹאשׁ הַשָּׁנָה ב׳", "Rosh Hashana 2", "Rosh Hashana 2", "Rosh Hashanah 2"],
+        YomKippur => ["יום כיפור", "יוֹם כִּפּוּר", "Yom Kippur", "Yom Kippur", "Yom Kippur"],
+        Sukkos1 => ["סוכות א׳", "סֻכּוֹת א׳", "Sukkos 1", "Sukkot 1", "Sukkot 1"],
+        Sukkos2 => ["סוכות ב׳", "סֻכּוֹת ב׳", "Sukkos 2", "Sukkot 2", "Sukkot 2"],
+        Sukkos3 => ["סוכות ג׳", "סֻכּוֹת ג׳", "Sukkos 3", "Sukkot 3", "Sukkot 3"],
+        Sukkos4 => ["סוכות ד׳", "סֻכּוֹת ד׳", "Sukkos 4", "Sukkot 4", "Sukkot 4"],
+        Sukkos5 => ["סוכות ה׳", "סֻכּוֹת ה׳", "Sukkos 5", "Sukkot 5", "Sukkot 5"],
+        Sukkos6 => ["סוכות ו׳", "סֻכּוֹת ו׳", "Sukkos 6", "Sukkot 6", "Sukkot 6"],
+        Sukkos7 => ["הושענא רבה", "הוֹשַׁעְנָא רַבָּה", "Hoshana Rabbah", "Hoshana Rabba", "Hoshana Rabbah"],
+        ShminiAtzeres => ["שמיני עצרת", "שְׁמִינִי עֲצֶרֶת", "Shmini Atzeres", "Shemini Atzeret", "Shemini Atzeret"],
+        SimchasTorah => ["שמחת תורה", "שִׂמְחַת תּוֹרָה", "Simchas Torah", "Simchat Torah", "Simchat Torah"],
+        Pesach1 => ["פסח א׳", "פֶּסַח א׳", "Pesach 1", "Pesach 1", "Passover 1"],
+        Pesach2 => ["פסח ב׳", "פֶּסַח ב׳", "Pesach 2", "Pesach 2", "Passover 2"],
+        Pesach3 => ["פסח ג׳", "פֶּסַח ג׳", "Pesach 3", "Pesach 3", "Passover 3"],
+        Pesach4 => ["פסח ד׳", "פֶּסַח ד׳", "Pesach 4", "Pesach 4", "Passover 4"],
+        Pesach5 => ["פסח ה׳", "פֶּסַח ה׳", "Pesach 5", "Pesach 5", "Passover 5"],
+        Pesach6 => ["פסח ו׳", "פֶּסַח ו׳", "Pesach 6", "Pesach 6", "Passover 6"],
+        Pesach7 => ["פסח ז׳", "פֶּסַח ז׳", "Pesach 7", "Pesach 7", "Passover 7"],
+        Pesach8 => ["פסח ח׳", "פֶּסַח ח׳", "Pesach 8", "Pesach 8", "Passover 8"],
+        Shavuos1 => ["שבועות א׳", "שָׁבוּעוֹת א׳", "Shavuos 1", "Shavuot 1", "Shavuot 1"],
+        Shavuos2 => ["שבועות ב׳", "שָׁבוּעוֹת ב׳", "Shavuos 2", "Shavuot 2", "Shavuot 2"],
+    }
+}
+
+localized! {
+    Chol {
+        TzomGedalia => ["צום גדליה", "צוֹם גְּדַלְיָה", "Tzom Gedalia", "Tzom Gedalya", "Fast of Gedaliah"],
+        RoshChodeshCheshvan1 => ["ראש חודש חשון א׳", "רֹאשׁ חֹדֶשׁ חֶשְׁוָן א׳", "Rosh Chodesh Cheshvan 1", "Rosh Chodesh Heshvan 1", "Rosh Chodesh Cheshvan 1"],
+        RoshChodeshCheshvan2 => ["ראש חודש חשון ב׳", "רֹאשׁ חֹדֶשׁ חֶשְׁוָן ב׳", "Rosh Chodesh Cheshvan 2", "Rosh Chodesh Heshvan 2", "Rosh Chodesh Cheshvan 2"],
+        Chanukah1 => ["חנוכה א׳", "חֲנֻכָּה א׳", "Chanukah 1", "Hanukka 1", "Hanukkah 1"],
+        Chanukah2 => ["חנוכה ב׳", "חֲנֻכָּה ב׳", "Chanukah 2", "Hanukka 2", "Hanukkah 2"],
+        Chanukah3 => ["חנוכה ג׳", "חֲנֻכָּה ג׳", "Chanukah 3", "Hanukka 3", "Hanukkah 3"],
+        Chanukah4 => ["חנוכה ד׳", "חֲנֻכָּה ד׳", "Chanukah 4", "Hanukka 4", "Hanukkah 4"],
+        Chanukah5 => ["חנוכה ה׳", "חֲנֻכָּה ה׳", "Chanukah 5", "Hanukka 5", "Hanukkah 5"],
+        Chanukah6 => ["חנוכה ו׳", "חֲנֻכָּה ו׳", "Chanukah 6", "Hanukka 6", "Hanukkah 6"],
+        Chanukah7 => ["חנוכה ז׳", "חֲנֻכָּה ז׳", "Chanukah 7", "Hanukka 7", "Hanukkah 7"],
+        Chanukah8 => ["חנוכה ח׳", "חֲנֻכָּה ח׳", "Chanukah 8", "Hanukka 8", "Hanukkah 8"],
+        TenTeves => ["עשרה בטבת", "עֲשָׂרָה בְּטֵבֵת", "Asara B'Teves", "Asara B'Tevet", "Tenth of Tevet"],
+        RoshChodeshShvat => ["ראש חודש שבט", "רֹאשׁ חֹדֶשׁ שְׁבָט", "Rosh Chodesh Shvat", "Rosh Chodesh Shevat", "Rosh Chodesh Shevat"],
+        RoshChodeshNissan => ["ראש חודש ניסן", "רֹאשׁ חֹדֶשׁ נִיסָן", "Rosh Chodesh Nissan", "Rosh Chodesh Nisan", "Rosh Chodesh Nisan"],
+        RoshChodeshIyar1 => ["ראש חודש אייר א׳", "רֹאשׁ חֹדֶשׁ אִיָּר א׳", "Rosh Chodesh Iyar 1", "Rosh Chodesh Iyar 1", "Rosh Chodesh Iyar 1"],
+        RoshChodeshIyar2 => ["ראש חודש אייר ב׳", "רֹאשׁ חֹדֶשׁ אִיָּר ב׳", "Rosh Chodesh Iyar 2", "Rosh Chodesh Iyar 2", "Rosh Chodesh Iyar 2"],
+        RoshChodeshSivan => ["ראש חודש סיון", "רֹאשׁ חֹדֶשׁ סִיוָן", "Rosh Chodesh Sivan", "Rosh Chodesh Sivan", "Rosh Chodesh Sivan"],
+        RoshChodeshTammuz1 => ["ראש חודש תמוז א׳", "רֹאשׁ חֹדֶשׁ תַּמּוּז א׳", "Rosh Chodesh Tammuz 1", "Rosh Chodesh Tamuz 1", "Rosh Chodesh Tammuz 1"],
+        RoshChodeshTammuz2 => ["ראש חודש תמוז ב׳", "רֹאשׁ חֹדֶשׁ תַּמּוּז ב׳", "Rosh Chodesh Tammuz 2", "Rosh Chodesh Tamuz 2", "Rosh Chodesh Tammuz 2"],
+        RoshChodeshAv => ["ראש חודש אב", "רֹאשׁ חֹדֶשׁ אָב", "Rosh Chodesh Av", "Rosh Chodesh Av", "Rosh Chodesh Av"],
+        RoshChodeshElul1 => ["ראש חודש אלול א׳", "רֹאשׁ חֹדֶשׁ אֱלוּל א׳", "Rosh Chodesh Elul 1", "Rosh Chodesh Elul 1", "Rosh Chodesh Elul 1"],
+        RoshChodeshElul2 => ["ראש חודש אלול ב׳", "רֹאשׁ חֹדֶשׁ אֱלוּל ב׳", "Rosh Chodesh Elul 2", "Rosh Chodesh Elul 2", "Rosh Chodesh Elul 2"],
+        RoshChodeshKislev1 => ["ראש חודש כסלו א׳", "רֹאשׁ חֹדֶשׁ כִּסְלֵו א׳", "Rosh Chodesh Kislev 1", "Rosh Chodesh Kislev 1", "Rosh Chodesh Kislev 1"],
+        RoshChodeshKislev2 => ["ראש חודש כסלו ב׳", "רֹאשׁ חֹדֶשׁ כִּסְלֵו ב׳", "Rosh Chodesh Kislev 2", "Rosh Chodesh Kislev 2", "Rosh Chodesh Kislev 2"],
+        RoshChodeshKislev => ["ראש חודש כסלו", "רֹאשׁ חֹדֶשׁ כִּסְלֵו", "Rosh Chodesh Kislev", "Rosh Chodesh Kislev", "Rosh Chodesh Kislev"],
+        RoshChodeshTeves1 => ["ראש חודש טבת א׳", "רֹאשׁ חֹדֶשׁ טֵבֵת א׳", "Rosh Chodesh Teves 1", "Rosh Chodesh Tevet 1", "Rosh Chodesh Tevet 1"],
+        RoshChodeshTeves2 => ["ראש חודש טבת ב׳", "רֹאשׁ חֹדֶשׁ טֵבֵת ב׳", "Rosh Chodesh Teves 2", "Rosh Chodesh Tevet 2", "Rosh Chodesh Tevet 2"],
+        RoshChodeshTeves => ["ראש חודש טבת", "רֹאשׁ חֹדֶשׁ טֵבֵת", "Rosh Chodesh Teves", "Rosh Chodesh Tevet", "Rosh Chodesh Tevet"],
+        RoshChodeshAdar1 => ["ראש חודש אדר א׳", "רֹאשׁ חֹדֶשׁ אֲדָר א׳", "Rosh Chodesh Adar 1", "Rosh Chodesh Adar 1", "Rosh Chodesh Adar 1"],
+        RoshChodeshAdar2 => ["ראש חודש אדר ב׳", "רֹאשׁ חֹדֶשׁ אֲדָר ב׳", "Rosh Chodesh Adar 2", "Rosh Chodesh Adar 2", "Rosh Chodesh Adar 2"],
+        TaanisEsther => ["תענית אסתר", "תַּעֲנִית אֶסְתֵּר", "Taanis Esther", "Taanit Ester", "Fast of Esther"],
+        Purim => ["פורים", "פּוּרִים", "Purim", "Purim", "Purim"],
+        ShushanPurim => ["שושן פורים", "שׁוּשַׁן פּוּרִים", "Shushan Purim", "Shushan Purim", "Shushan Purim"],
+        RoshChodeshAdarRishon1 => ["ראש חודש אדר א׳ א׳", "רֹאשׁ חֹדֶשׁ אֲדָר א׳ א׳", "Rosh Chodesh Adar Rishon 1", "Rosh Chodesh Adar Alef 1", "Rosh Chodesh Adar I 1"],
+        RoshChodeshAdarRishon2 => ["ראש חודש אדר א׳ ב׳", "רֹאשׁ חֹדֶשׁ אֲדָר א׳ ב׳", "Rosh Chodesh Adar Rishon 2", "Rosh Chodesh Adar Alef 2", "Rosh Chodesh Adar I 2"],
+        RoshChodeshAdarSheni1 => ["ראש חודש אדר ב׳ א׳", "רֹאשׁ חֹדֶשׁ אֲדָר ב׳ א׳", "Rosh Chodesh Adar Sheni 1", "Rosh Chodesh Adar Bet 1", "Rosh Chodesh Adar II 1"],
+        RoshChodeshAdarSheni2 => ["ראש חודש אדר ב׳ ב׳", "רֹאשׁ חֹדֶשׁ אֲדָר ב׳ ב׳", "Rosh Chodesh Adar Sheni 2", "Rosh Chodesh Adar Bet 2", "Rosh Chodesh Adar II 2"],
+        SeventeenTammuz => ["שבעה עשר בתמוז", "שִׁבְעָה עָשָׂר בְּתַמּוּז", "Shiva Asar B'Tammuz", "Shiva Asar B'Tamuz", "Seventeenth of Tammuz"],
+        NineAv => ["תשעה באב", "תִּשְׁעָה בְּאָב", "Tisha B'Av", "Tisha B'Av", "Ninth of Av"],
+    }
+}
+
+localized! {
+    SpecialParsha {
+        Shekalim => ["שקלים", "שְׁקָלִים", "Shekalim", "Shekalim", "Shekalim"],
+        Zachor => ["זכור", "זָכוֹר", "Zachor", "Zachor", "Zachor"],
+        Parah => ["פרה", "פָּרָה", "Parah", "Parah", "Parah"],
+        HaChodesh => ["החודש", "הַחֹדֶשׁ", "HaChodesh", "HaHodesh", "HaChodesh"],
+    }
+}
+
+localized! {
+    Parsha {
+        Vayelech => ["וילך", "וַיֵּלֶךְ", "Vayelech", "Vayelech", "Vayeilech"],
+        Haazinu => ["האזינו", "הַאֲזִינוּ", "Haazinu", "Haazinu", "Ha'azinu"],
+        Bereishis => ["בראשית", "בְּרֵאשִׁית", "Bereishis", "Bereshit", "Bereshit"],
+        Noach => ["נח", "נֹחַ", "Noach", "Noach", "Noach"],
+        LechLecha => ["לך לך", "לֶךְ לְךָ", "Lech Lecha", "Lech Lecha", "Lech-Lecha"],
+        Vayeira => ["וירא", "וַיֵּרָא", "Vayeira", "Vayera", "Vayera"],
+        ChayeiSara => ["חיי שרה", "חַיֵּי שָׂרָה", "Chayei Sara", "Hayei Sarah", "Chayei Sara"],
+        Toldos => ["תולדות", "תּוֹלְדֹת", "Toldos", "Toldot", "Toldot"],
+        Vayetzei => ["ויצא", "וַיֵּצֵא", "Vayetzei", "Vayetze", "Vayetzei"],
+        Vayishlach => ["וישלח", "וַיִּשְׁלַח", "Vayishlach", "Vayishlach", "Vayishlach"],
+        Vayeshev => ["וישב", "וַיֵּשֶׁב", "Vayeshev", "Vayeshev", "Vayeshev"],
+        Miketz => ["מקץ", "מִקֵּץ", "Miketz", "Miketz", "Miketz"],
+        Vayigash => ["ויגש", "וַיִּגַּשׁ", "Vayigash", "Vayigash", "Vayigash"],
+        Vayechi => ["ויחי", "וַיְחִי", "Vayechi", "Vayehi", "Vayechi"],
+        Shemos => ["שמות", "שְׁמוֹת", "Shemos", "Shemot", "Shemot"],
+        Vaeira => ["וארא", "וָאֵרָא", "Vaeira", "Vaera", "Vaera"],
+        Bo => ["בא", "בֹּא", "Bo", "Bo", "Bo"],
+        Beshalach => ["בשלח", "בְּשַׁלַּח", "Beshalach", "Beshalach", "Beshalach"],
+        Yisro => ["יתרו", "יִתְרוֹ", "Yisro", "Yitro", "Yitro"],
+        Mishpatim => ["משפטים", "מִשְׁפָּטִים", "Mishpatim", "Mishpatim", "Mishpatim"],
+        Terumah => ["תרומה", "תְּרוּמָה", "Terumah", "Terumah", "Terumah"],
+        Tetzaveh => ["תצוה", "תְּצַוֶּה", "Tetzaveh", "Tetzaveh", "Tetzaveh"],
+        KiSisa => ["כי תשא", "כִּי תִשָּׂא", "Ki Sisa", "Ki Tisa", "Ki Tisa"],
+        VayakhelPikudei => ["ויקהל-פקודי", "וַיַּקְהֵל־פְקוּדֵי", "Vayakhel-Pikudei", "Vayakhel-Pekudei", "Vayakhel-Pekudei"],
+        Vayakhel => ["ויקהל", "וַיַּקְהֵל", "Vayakhel", "Vayakhel", "Vayakhel"],
+        Pikudei => ["פקודי", "פְקוּדֵי", "Pikudei", "Pekudei", "Pekudei"],
+        Vayikra => ["ויקרא", "וַיִּקְרָא", "Vayikra", "Vayikra", "Vayikra"],
+        Tzav => ["צו", "צַו", "Tzav", "Tzav", "Tzav"],
+        Shemini => ["שמיני", "שְׁמִינִי", "Shemini", "Shmini", "Shmini"],
+        TazriyaMetzorah => ["תזריע-מצורע", "תַזְרִיעַ־מְצֹרָע", "Tazriya-Metzorah", "Tazria-Metzora", "Tazria-Metzora"],
+        Tazriya => ["תזריע", "תַזְרִיעַ", "Tazriya", "Tazria", "Tazria"],
+        Metzorah => ["מצורע", "מְצֹרָע", "Metzorah", "Metzora", "Metzora"],
+        AchareiMosKedoshim => ["אחרי מות-קדושים", "אַחֲרֵי מוֹת־קְדֹשִׁים", "Acharei Mos-Kedoshim", "Aharei Mot-Kedoshim", "Achrei Mot-Kedoshim"],
+        AchareiMos => ["אחרי מות", "אַחֲרֵי מוֹת", "Acharei Mos", "Aharei Mot", "Achrei Mot"],
+        Kedoshim => ["קדושים", "קְדֹשִׁים", "Kedoshim", "Kedoshim", "Kedoshim"],
+        Emor => ["אמור", "אֱמֹר", "Emor", "Emor", "Emor"],
+        BeharBechukosai => ["בהר-בחוקותי", "בְּהַר־בְּחֻקֹּתַי", "Behar-Bechukosai", "Behar-Behukotai", "Behar-Bechukotai"],
+        Behar => ["בהר", "בְּהַר", "Behar", "Behar", "Behar"],
+        Bechukosai => ["בחוקותי", "בְּחֻקֹּתַי", "Bechukosai", "Behukotai", "Bechukotai"],
+        Bamidbar => ["במדבר", "בְּמִדְבַּר", "Bamidbar", "Bamidbar", "Bamidbar"],
+        Naso => ["נשא", "נָשֹׂא", "Naso", "Naso", "Nasso"],
+        Behaaloscha => ["בהעלותך", "בְּהַעֲלֹתְךָ", "Behaaloscha", "Behaalotcha", "Beha'alotcha"],
+        Shlach => ["שלח", "שְׁלַח", "Shlach", "Shlach", "Sh'lach"],
+        Korach => ["קרח", "קֹרַח", "Korach", "Korah", "Korach"],
+        ChukasBalak => ["חוקת-בלק", "חֻקַּת־בָּלָק", "Chukas-Balak", "Hukat-Balak", "Chukat-Balak"],
+        Chukas => ["חוקת", "חֻקַּת", "Chukas", "Hukat", "Chukat"],
+        Balak => ["בלק", "בָּלָק", "Balak", "Balak", "Balak"],
+        Pinchas => ["פינחס", "פִּינְחָס", "Pinchas", "Pinhas", "Pinchas"],
+        MatosMaasei => ["מטות-מסעי", "מַטּוֹת־מַסְעֵי", "Matos-Maasei", "Matot-Masei", "Matot-Masei"],
+        Matos => ["מטות", "מַטּוֹת", "Matos", "Matot", "Matot"],
+        Maasei => ["מסעי", "מַסְעֵי", "Maasei", "Masei", "Masei"],
+        Devarim => ["דברים", "דְּבָרִים", "Devarim", "Devarim", "Devarim"],
+        Vaeschanan => ["ואתחנן", "וָאֶתְחַנַּן", "Vaeschanan", "Vaethanan", "Vaetchanan"],
+        Eikev => ["עקב", "עֵקֶב", "Eikev", "Ekev", "Eikev"],
+        Reeh => ["ראה", "רְאֵה", "Reeh", "Re'eh", "Re'eh"],
+        Shoftim => ["שופטים", "שֹׁפְטִים", "Shoftim", "Shoftim", "Shoftim"],
+        KiSeitzei => ["כי תצא", "כִּי תֵצֵא", "Ki Seitzei", "Ki Tetze", "Ki Teitzei"],
+        KiSavoh => ["כי תבוא", "כִּי תָבוֹא", "Ki Savo", "Ki Tavo", "Ki Tavo"],
+        NitzavimVayelech => ["נצבים-וילך", "נִצָּבִים־וַיֵּלֶךְ", "Nitzavim-Vayelech", "Nitzavim-Vayelech", "Nitzavim-Vayeilech"],
+        Nitzavim => ["נצבים", "נִצָּבִים", "Nitzavim", "Nitzavim", "Nitzavim"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HebrewYear;
+
+    const LOCALES: [Locale; 5] = [
+        Locale::Hebrew,
+        Locale::HebrewNikud,
+        Locale::Ashkenazi,
+        Locale::Sephardi,
+        Locale::English,
+    ];
+
+    fn check_names<T: Localized + fmt::Display>(x: T) {
+        assert_eq!(x.to_string(), x.localized(Locale::Ashkenazi));
+        for locale in LOCALES.iter() {
+            let name = x.localized(*locale);
+            assert!(!name.is_empty());
+            let is_hebrew = name.chars().any(|c| ('\u{05D0}'..='\u{05EA}').contains(&c));
+            let has_nikud = name.chars().any(|c| ('\u{05B0}'..='\u{05BC}').contains(&c));
+            match locale {
+                Locale::Hebrew => assert!(is_hebrew && !has_nikud, "{}", name),
+                Locale::HebrewNikud => assert!(is_hebrew, "{}", name),
+                _ => assert!(name.is_ascii(), "{}", name),
+            }
+        }
+    }
+
+    #[test]
+    fn every_name_is_localized() {
+        for day in Day::all() {
+            check_names(day);
+        }
+        for month in HebrewMonth::all() {
+            check_names(month);
+            //Every name of a month can be parsed back.
+            for locale in LOCALES.iter() {
+                assert_eq!(month.localized(*locale).parse(), Ok(month));
+            }
+        }
+        check_names(Location::Israel);
+        check_names(Location::Chul);
+        let types = [
+            TorahReadingType::YomTov,
+            TorahReadingType::Chol,
+            TorahReadingType::Shabbos,
+            TorahReadingType::SpecialParsha,
+        ];
+        for t in types.iter() {
+            check_names(*t);
+        }
+        for year in 5700..5800 {
+            let year = HebrewYear::new(year).unwrap();
+            check_names(year.year_type());
+            for location in [Location::Israel, Location::Chul].iter() {
+                for day in year.get_holidays(*location, &types).iter() {
+                    check_names(day.name());
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,30 @@
+use core::cmp::Ordering;
+use core::convert::TryFrom;
 use serde::*;
-use std::cmp::Ordering;
 
 use crate::convert::HebrewDate;
+use crate::prelude::{ConversionError, TorahReadingType};
 
-#[derive(Debug, Eq, Copy, Clone, Serialize)]
+#[derive(Debug, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "TorahReadingDayFields")]
 /// This struct holds a day on which the Torah is read.
 ///
-/// You can get the Hebrew Date and the Torah reading.
+/// You can get the Hebrew Date and the Torah reading. To include the Gregorian date when it's
+/// serialized, see
+/// [serialization::with_gregorian](../../heca_lib/serialization/with_gregorian/index.html).
+///
+/// Deserializing it fails if the reading isn't read on that day, in Israel or in the Diaspora.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+///
+/// let json = r#"{"day":{"day":10,"month":"Tishrei","year":5779},"name":{"YomTov":"YomKippur"}}"#;
+/// assert_eq!(serde_json::from_str::<TorahReadingDay>(json).unwrap().name(), TorahReading::YomTov(YomTov::YomKippur));
+/// let json = r#"{"day":{"day":1,"month":"Nissan","year":5779},"name":{"YomTov":"YomKippur"}}"#;
+/// assert!(serde_json::from_str::<TorahReadingDay>(json).is_err());
+/// ```
 pub struct TorahReadingDay {
     pub(crate) day: HebrewDate,
     pub(crate) name: TorahReading,
@@ -22,6 +40,43 @@ impl TorahReadingDay {
     pub fn name(&self) -> TorahReading {
         self.name
     }
+
+    // Returns the TorahReadingDay if the reading is read on that day (in Israel or in the Diaspora),
+    // or a ParseError if it isn't.
+    pub(crate) fn checked(day: HebrewDate, name: TorahReading) -> Result<Self, ConversionError> {
+        let reading_type = match name {
+            TorahReading::YomTov(_) => TorahReadingType::YomTov,
+            TorahReading::Chol(_) => TorahReadingType::Chol,
+            TorahReading::Shabbos(_) => TorahReadingType::Shabbos,
+            TorahReading::SpecialParsha(_) => TorahReadingType::SpecialParsha,
+        };
+        let reading_day = TorahReadingDay { day, name };
+        let year = day.hebrew_year();
+        if [Location::Israel, Location::Chul].iter().any(|location| {
+            year.get_holidays(*location, &[reading_type])
+                .iter()
+                .any(|x| x.day == day && x.name == name)
+        }) {
+            Ok(reading_day)
+        } else {
+            Err(ConversionError::ParseError)
+        }
+    }
+}
+
+//The serialized form of a TorahReadingDay.
+#[derive(Deserialize)]
+#[serde(rename = "TorahReadingDay")]
+struct TorahReadingDayFields {
+    day: HebrewDate,
+    name: TorahReading,
+}
+
+impl TryFrom<TorahReadingDayFields> for TorahReadingDay {
+    type Error = ConversionError;
+    fn try_from(fields: TorahReadingDayFields) -> Result<TorahReadingDay, ConversionError> {
+        TorahReadingDay::checked(fields.day, fields.name)
+    }
 }
 
 impl PartialOrd for TorahReadingDay {
@@ -45,37 +100,39 @@ impl PartialEq for TorahReadingDay {
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum Location {
-    Israel,
-    Chul,
+    Israel = 0,
+    Chul = 1,
 }
 
 /// Yom Tov, including Rosh Hashana, Yom Kippur and Chol HaMoed
 #[derive(Eq, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum YomTov {
-    RoshHashanah1,
-    RoshHashanah2,
-    YomKippur,
-    Sukkos1,
-    Sukkos2,
-    Sukkos3,
-    Sukkos4,
-    Sukkos5,
-    Sukkos6,
-    Sukkos7,
-    ShminiAtzeres,
-    SimchasTorah,
-    Pesach1,
-    Pesach2,
-    Pesach3,
-    Pesach4,
-    Pesach5,
-    Pesach6,
-    Pesach7,
-    Pesach8,
-    Shavuos1,
-    Shavuos2,
+    RoshHashanah1 = 0,
+    RoshHashanah2 = 1,
+    YomKippur = 2,
+    Sukkos1 = 3,
+    Sukkos2 = 4,
+    Sukkos3 = 5,
+    Sukkos4 = 6,
+    Sukkos5 = 7,
+    Sukkos6 = 8,
+    Sukkos7 = 9,
+    ShminiAtzeres = 10,
+    SimchasTorah = 11,
+    Pesach1 = 12,
+    Pesach2 = 13,
+    Pesach3 = 14,
+    Pesach4 = 15,
+    Pesach5 = 16,
+    Pesach6 = 17,
+    Pesach7 = 18,
+    Pesach8 = 19,
+    Shavuos1 = 20,
+    Shavuos2 = 21,
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Serialize, Deserialize)]
@@ -88,117 +145,120 @@ pub enum TorahReading {
 
 /// Special Parshas read every winter
 #[derive(Eq, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum SpecialParsha {
-    Shekalim,
-    Zachor,
-    Parah,
-    HaChodesh,
+    Shekalim = 0,
+    Zachor = 1,
+    Parah = 2,
+    HaChodesh = 3,
 }
 /// Possible weekday Torah readings
 #[derive(Eq, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum Chol {
-    TzomGedalia,
-    RoshChodeshCheshvan1,
-    RoshChodeshCheshvan2,
-    Chanukah1,
-    Chanukah2,
-    Chanukah3,
-    Chanukah4,
-    Chanukah5,
-    Chanukah6,
-    Chanukah7,
-    Chanukah8,
-    TenTeves,
-    RoshChodeshShvat,
-    RoshChodeshNissan,
-    RoshChodeshIyar1,
-    RoshChodeshIyar2,
-    RoshChodeshSivan,
-    RoshChodeshTammuz1,
-    RoshChodeshTammuz2,
-    RoshChodeshAv,
-    RoshChodeshElul1,
-    RoshChodeshElul2,
-    RoshChodeshKislev1,
-    RoshChodeshKislev2,
-    RoshChodeshKislev,
-    RoshChodeshTeves1,
-    RoshChodeshTeves2,
-    RoshChodeshTeves,
-    RoshChodeshAdar1,
-    RoshChodeshAdar2,
-    TaanisEsther,
-    Purim,
-    ShushanPurim,
-    RoshChodeshAdarRishon1,
-    RoshChodeshAdarRishon2,
-    RoshChodeshAdarSheni1,
-    RoshChodeshAdarSheni2,
-    SeventeenTammuz,
-    NineAv,
+    TzomGedalia = 0,
+    RoshChodeshCheshvan1 = 1,
+    RoshChodeshCheshvan2 = 2,
+    Chanukah1 = 3,
+    Chanukah2 = 4,
+    Chanukah3 = 5,
+    Chanukah4 = 6,
+    Chanukah5 = 7,
+    Chanukah6 = 8,
+    Chanukah7 = 9,
+    Chanukah8 = 10,
+    TenTeves = 11,
+    RoshChodeshShvat = 12,
+    RoshChodeshNissan = 13,
+    RoshChodeshIyar1 = 14,
+    RoshChodeshIyar2 = 15,
+    RoshChodeshSivan = 16,
+    RoshChodeshTammuz1 = 17,
+    RoshChodeshTammuz2 = 18,
+    RoshChodeshAv = 19,
+    RoshChodeshElul1 = 20,
+    RoshChodeshElul2 = 21,
+    RoshChodeshKislev1 = 22,
+    RoshChodeshKislev2 = 23,
+    RoshChodeshKislev = 24,
+    RoshChodeshTeves1 = 25,
+    RoshChodeshTeves2 = 26,
+    RoshChodeshTeves = 27,
+    RoshChodeshAdar1 = 28,
+    RoshChodeshAdar2 = 29,
+    TaanisEsther = 30,
+    Purim = 31,
+    ShushanPurim = 32,
+    RoshChodeshAdarRishon1 = 33,
+    RoshChodeshAdarRishon2 = 34,
+    RoshChodeshAdarSheni1 = 35,
+    RoshChodeshAdarSheni2 = 36,
+    SeventeenTammuz = 37,
+    NineAv = 38,
 }
 
 /// Weekly Torah Portion
 #[derive(Clone, Debug, Eq, PartialEq, Copy, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum Parsha {
-    Vayelech,
-    Haazinu,
-    Bereishis,
-    Noach,
-    LechLecha,
-    Vayeira,
-    ChayeiSara,
-    Toldos,
-    Vayetzei,
-    Vayishlach,
-    Vayeshev,
-    Miketz,
-    Vayigash,
-    Vayechi,
-    Shemos,
-    Vaeira,
-    Bo,
-    Beshalach,
-    Yisro,
-    Mishpatim,
-    Terumah,
-    Tetzaveh,
-    KiSisa,
-    VayakhelPikudei,
-    Vayakhel,
-    Pikudei,
-    Vayikra,
-    Tzav,
-    Shemini,
-    TazriyaMetzorah,
-    Tazriya,
-    Metzorah,
-    AchareiMosKedoshim,
-    AchareiMos,
-    Kedoshim,
-    Emor,
-    BeharBechukosai,
-    Behar,
-    Bechukosai,
-    Bamidbar,
-    Naso,
-    Behaaloscha,
-    Shlach,
-    Korach,
-    ChukasBalak,
-    Chukas,
-    Balak,
-    Pinchas,
-    MatosMaasei,
-    Matos,
-    Maasei,
-    Devarim,
-    Vaeschanan,
-    Eikev,
-    Reeh,
-    Shoftim,
-    KiSeitzei,
-    KiSavoh,
-    NitzavimVayelech,
-    Nitzavim,
+    Vayelech = 0,
+    Haazinu = 1,
+    Bereishis = 2,
+    Noach = 3,
+    LechLecha = 4,
+    Vayeira = 5,
+    ChayeiSara = 6,
+    Toldos = 7,
+    Vayetzei = 8,
+    Vayishlach = 9,
+    Vayeshev = 10,
+    Miketz = 11,
+    Vayigash = 12,
+    Vayechi = 13,
+    Shemos = 14,
+    Vaeira = 15,
+    Bo = 16,
+    Beshalach = 17,
+    Yisro = 18,
+    Mishpatim = 19,
+    Terumah = 20,
+    Tetzaveh = 21,
+    KiSisa = 22,
+    VayakhelPikudei = 23,
+    Vayakhel = 24,
+    Pikudei = 25,
+    Vayikra = 26,
+    Tzav = 27,
+    Shemini = 28,
+    TazriyaMetzorah = 29,
+    Tazriya = 30,
+    Metzorah = 31,
+    AchareiMosKedoshim = 32,
+    AchareiMos = 33,
+    Kedoshim = 34,
+    Emor = 35,
+    BeharBechukosai = 36,
+    Behar = 37,
+    Bechukosai = 38,
+    Bamidbar = 39,
+    Naso = 40,
+    Behaaloscha = 41,
+    Shlach = 42,
+    Korach = 43,
+    ChukasBalak = 44,
+    Chukas = 45,
+    Balak = 46,
+    Pinchas = 47,
+    MatosMaasei = 48,
+    Matos = 49,
+    Maasei = 50,
+    Devarim = 51,
+    Vaeschanan = 52,
+    Eikev = 53,
+    Reeh = 54,
+    Shoftim = 55,
+    KiSeitzei = 56,
+    KiSavoh = 57,
+    NitzavimVayelech = 58,
+    Nitzavim = 59,
 }
@@ -1,92 +1,155 @@
+mod locale;
 mod location;
-use chrono::Utc;
+mod place;
+mod variants;
+use crate::convert::day_of_week;
+#[cfg(feature = "chrono")]
+use crate::convert::EPOCH_TIMESTAMP;
+#[cfg(all(feature = "std", feature = "chrono"))]
+use chrono::NaiveTime;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+use core::fmt;
+#[doc(inline)]
+pub use locale::*;
 #[doc(inline)]
 pub use location::*;
+#[doc(inline)]
+pub use place::*;
 use serde::{Deserialize, Serialize};
-use std::fmt;
 
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Copy, Clone, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum Day {
-    Sunday,
-    Monday,
-    Tuesday,
-    Wednesday,
-    Thursday,
-    Friday,
-    Shabbos,
+    Sunday = 0,
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Shabbos = 6,
 }
 
-/// Notes: This panics if input is larger than 6, so this will be converted to a TryFrom in a future release.
-impl From<u64> for Day {
-    fn from(input: u64) -> Self {
-        match input {
-            0 => Day::Sunday,
-            1 => Day::Monday,
-            2 => Day::Tuesday,
-            3 => Day::Wednesday,
-            4 => Day::Thursday,
-            5 => Day::Friday,
-            6 => Day::Shabbos,
-            _ => panic!(format!("{} Is out of bounds", input)),
+/// The Molad of a month.
+///
+/// It's serialized as its day number, hours, minutes and Chalakim (as returned by `day_number`,
+/// `hours`, `minutes` and `get_chalakim`).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "MoladFields", into = "MoladFields")]
+pub struct Molad {
+    pub(crate) minutes_since_epoch: i64,
+    pub(crate) remainder: u16,
+}
+
+//The serialized form of a Molad.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Molad")]
+struct MoladFields {
+    day_number: i64,
+    hours: u8,
+    minutes: u8,
+    chalakim: u16,
+}
+
+impl From<Molad> for MoladFields {
+    fn from(molad: Molad) -> MoladFields {
+        MoladFields {
+            day_number: molad.day_number(),
+            hours: molad.hours(),
+            minutes: molad.minutes(),
+            chalakim: molad.get_chalakim(),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Ord, PartialOrd)]
-pub struct Molad {
-    pub(crate) day: chrono::DateTime<Utc>,
-    pub(crate) remainder: u16,
+impl core::convert::TryFrom<MoladFields> for Molad {
+    type Error = ConversionError;
+    fn try_from(fields: MoladFields) -> Result<Molad, ConversionError> {
+        if fields.hours >= 24 || fields.minutes >= 60 || fields.chalakim >= 18 {
+            return Err(ConversionError::ParseError);
+        }
+        let minutes_since_epoch = fields
+            .day_number
+            .checked_mul(24 * 60)
+            .and_then(|x| x.checked_add(i64::from(fields.hours) * 60 + i64::from(fields.minutes)))
+            .ok_or(ConversionError::ParseError)?;
+        Ok(Molad {
+            minutes_since_epoch,
+            remainder: fields.chalakim,
+        })
+    }
 }
 
 impl Molad {
-    pub fn get_day_utc(&self) -> chrono::DateTime<Utc> {
-        self.day
+    /// Returns the minute of the Molad, in UTC. The Chalakim after that minute are returned by
+    /// `get_chalakim`.
+    #[cfg(feature = "chrono")]
+    pub fn get_day_utc(&self) -> DateTime<Utc> {
+        Utc.timestamp(EPOCH_TIMESTAMP + self.minutes_since_epoch * 60, 0)
     }
+    /// Returns the amount of Chalakim after the minute of the Molad (there are 18 Chalakim in a
+    /// minute).
     pub fn get_chalakim(&self) -> u16 {
         self.remainder
     }
+    /// Returns the day number (as returned by `HebrewDate::day_number`) of the Hebrew day that the
+    /// Molad is on. The day starts at 6:00 PM UTC.
+    pub fn day_number(&self) -> i64 {
+        self.minutes_since_epoch.div_euclid(24 * 60)
+    }
+    /// Returns the day of the week that the Molad is on.
+    pub fn weekday(&self) -> Day {
+        day_of_week(self.day_number())
+    }
+    /// Returns the amount of whole hours between the start of the day (6:00 PM UTC) and the Molad,
+    /// as the Molad is traditionally counted.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::HebrewYear;
+    ///
+    /// // The Molad of Tishrei 5780 was on Sunday, 11 hours, 50 minutes and 5 Chalakim after the
+    /// // start of the day.
+    /// let molad = HebrewYear::new(5780)?.get_molad(HebrewMonth::Tishrei)?;
+    /// assert_eq!(molad.weekday(), Day::Sunday);
+    /// assert_eq!(molad.hours(), 11);
+    /// assert_eq!(molad.minutes(), 50);
+    /// assert_eq!(molad.get_chalakim(), 5);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn hours(&self) -> u8 {
+        (self.minutes_since_epoch.rem_euclid(24 * 60) / 60) as u8
+    }
+    /// Returns the amount of minutes after the hour returned by `hours`.
+    pub fn minutes(&self) -> u8 {
+        self.minutes_since_epoch.rem_euclid(60) as u8
+    }
 }
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd)]
+#[repr(u8)]
 pub enum HebrewMonth {
-    Tishrei,
-    Cheshvan,
-    Kislev,
-    Teves,
-    Shvat,
-    Adar,
-    Adar1,
-    Adar2,
-    Nissan,
-    Iyar,
-    Sivan,
-    Tammuz,
-    Av,
-    Elul,
+    Tishrei = 0,
+    Cheshvan = 1,
+    Kislev = 2,
+    Teves = 3,
+    Shvat = 4,
+    Adar = 5,
+    Adar1 = 6,
+    Adar2 = 7,
+    Nissan = 8,
+    Iyar = 9,
+    Sivan = 10,
+    Tammuz = 11,
+    Av = 12,
+    Elul = 13,
 }
-impl From<u64> for HebrewMonth {
-    fn from(input: u64) -> Self {
-        match input {
-            0 => HebrewMonth::Tishrei,
-            1 => HebrewMonth::Cheshvan,
-            2 => HebrewMonth::Kislev,
-            3 => HebrewMonth::Teves,
-            4 => HebrewMonth::Shvat,
-            5 => HebrewMonth::Adar,
-            6 => HebrewMonth::Adar1,
-            7 => HebrewMonth::Adar2,
-            8 => HebrewMonth::Nissan,
-            9 => HebrewMonth::Iyar,
-            10 => HebrewMonth::Sivan,
-            11 => HebrewMonth::Tammuz,
-            12 => HebrewMonth::Av,
-            13 => HebrewMonth::Elul,
-            _ => panic!(format!("{} Is out of bounds", input)),
-        }
-    }
-}
-
 ///Occurs when failing to get a Hebrew Date.
+///
+///This enum is non-exhaustive, as new errors may be added in minor versions.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ConversionError {
     /// Occurs when attempting to get an Adar 1 or Adar 2 in a non-leap year.
     ///
@@ -129,7 +192,7 @@ pub enum ConversionError {
     /// assert_eq!(result.unwrap_err(),ConversionError::IsLeapYear);
     /// ```
     IsLeapYear,
-    /// Occurs when attempting to get a year that is before the epoch (currently: year 3764/4).
+    /// Occurs when attempting to get a date before the year 1 (3761 BCE).
     ///
     /// # Example:
     /// ```
@@ -137,13 +200,71 @@ pub enum ConversionError {
     /// # use heca_lib::HebrewDate;
     /// # use std::num::NonZeroI8;
     /// #
-    /// let result = HebrewDate::from_ymd(2448,HebrewMonth::Nissan,NonZeroI8::new(15).unwrap()); // What was the English day of the Exodus?
+    /// let result = HebrewDate::from_ymd(0,HebrewMonth::Nissan,NonZeroI8::new(15).unwrap());
     /// assert!(!result.is_ok());
     /// assert_eq!(result.unwrap_err(),ConversionError::YearTooSmall);
     /// ```
     YearTooSmall,
+    /// Occurs when converting a date which is too far in the future for the type it's converted to
+    /// (such as chrono's NaiveDate, which only goes up to the year 262143), or when the year is
+    /// after the year 974,245,223,496, whose Molad can't be calculated.
+    ///
+    /// # Example:
+    /// ```
+    /// # #[cfg(feature = "chrono")]
+    /// # fn main() -> Result<(), heca_lib::prelude::ConversionError> {
+    /// # use heca_lib::prelude::*;
+    /// # use heca_lib::HebrewDate;
+    /// # use chrono::NaiveDate;
+    /// # use std::convert::TryFrom;
+    /// # use std::num::NonZeroI8;
+    /// #
+    /// let date = HebrewDate::from_ymd(300000,HebrewMonth::Tishrei,NonZeroI8::new(1).unwrap())?;
+    /// assert_eq!(NaiveDate::try_from(date),Err(ConversionError::YearTooLarge));
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "chrono"))]
+    /// # fn main() {}
+    /// ```
+    YearTooLarge,
+    /// Occurs when the day starts at sunset, but the sun doesn't set on that day (such as near
+    /// the poles in the summer).
+    ///
+    /// # Example:
+    /// ```
+    /// # #[cfg(all(feature = "std", feature = "chrono"))]
+    /// # fn main() {
+    /// # use heca_lib::prelude::*;
+    /// # use heca_lib::HebrewDate;
+    /// # use chrono::prelude::*;
+    /// #
+    /// let svalbard = DayBoundary::Sunset { latitude: 78.22, longitude: 15.65 };
+    /// let result = HebrewDate::from_datetime(&Utc.ymd(2019, 6, 21).and_hms(12, 0, 0), svalbard);
+    /// assert_eq!(result.unwrap_err(), ConversionError::NoSunset);
+    /// # }
+    /// # #[cfg(not(all(feature = "std", feature = "chrono")))]
+    /// # fn main() {}
+    /// ```
+    NoSunset,
+    /// Occurs when a string can't be parsed as a date or as one of the enums in the prelude, when a
+    /// number isn't the id of any variant of the enum, or when deserializing a value which doesn't
+    /// make sense (such as the 0th of a month).
+    ///
+    /// # Example:
+    /// ```
+    /// # use heca_lib::prelude::*;
+    /// # use heca_lib::HebrewDate;
+    /// # use std::convert::TryFrom;
+    /// #
+    /// let result = "10 Tishrei".parse::<HebrewDate>();
+    /// assert_eq!(result.unwrap_err(), ConversionError::ParseError);
+    /// assert_eq!(Parsha::try_from(200u8), Err(ConversionError::ParseError));
+    /// assert_eq!(Parsha::try_from(Parsha::Noach as u8), Ok(Parsha::Noach));
+    /// ```
+    ParseError,
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ConversionError {}
 
 impl fmt::Display for ConversionError {
@@ -163,23 +284,27 @@ impl fmt::Display for ConversionError {
             //ConversionError::MonthDoesntExist => write!(f, "Month doesn't exist"),
             ConversionError::YearTooSmall => write!(
                 f,
-                "Cannot build calendar for years below 3764 (After Creation)"
+                "Cannot build calendar for years below 1 (After Creation)"
             ),
+            ConversionError::YearTooLarge => write!(f, "The year is too large to convert"),
+            ConversionError::NoSunset => write!(f, "The sun doesn't set on that day"),
+            ConversionError::ParseError => write!(f, "Cannot parse the date"),
         }
     }
 }
 
 /// What Torah Readings are we looking for
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
 pub enum TorahReadingType {
     /// Yom Tov - Pesach, Shavuos, Sukkos, Shmini Atzeres/Simchas Torah, Rosh Hashana, Yom Kippur and Chol HaMoed.
-    YomTov,
+    YomTov = 0,
     /// Weekday Torah reading - Rosh Chodesh, Chanuka and Purim
-    Chol,
+    Chol = 1,
     /// Weekly Parsha Torah reading
-    Shabbos,
+    Shabbos = 2,
     /// One of the four special Torah portions read every winter (Shekalim, Zachor, Parah and HaChodesh).
-    SpecialParsha,
+    SpecialParsha = 3,
 }
 
 /// A Hebrew year can be defined by three variables:
@@ -263,20 +388,146 @@ pub enum TorahReadingType {
 /// ~~~
 ///
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
 pub enum MonthSchedule {
-    BaChaG,
-    BaShaH,
-    GaChaH,
-    HaKaZ,
-    HaShA,
-    ZaChA,
-    ZaShaG,
+    BaChaG = 0,
+    BaShaH = 1,
+    GaChaH = 2,
+    HaKaZ = 3,
+    HaShA = 4,
+    ZaChA = 5,
+    ZaShaG = 6,
+
+    BaChaH = 7,
+    BaShaZ = 8,
+    GaKaZ = 9,
+    HaChA = 10,
+    HaShaG = 11,
+    ZaChaG = 12,
+    ZaShaH = 13,
+}
+
+/// What to do when moving a date into a month that's too short for its day.
+///
+/// This can only happen with the 30th of a month, such as when moving the 30th of Cheshvan into a
+/// year where Cheshvan only has 29 days.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
+pub enum DayOverflow {
+    /// Use the last day of the month instead (the 30th becomes the 29th).
+    Clamp = 0,
+    /// Carry the extra day over into the next month (the 30th becomes the 1st of the next month).
+    Carry = 1,
+    /// Return a `ConversionError::TooManyDaysInMonth`.
+    Fail = 2,
+}
+
+/// Which Adar to use when moving a date in Adar of a regular year into a leap year.
+///
+/// A date in Adar 1 or Adar 2 moved into a regular year always ends up in Adar.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
+pub enum LeapAdar {
+    Adar1 = 0,
+    Adar2 = 1,
+}
+
+/// How to move a HebrewDate by months or years.
+///
+/// The default is to clamp the day to the end of the month and to move Adar to Adar 2 (which is
+/// when Purim is celebrated in a leap year).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ArithmeticPolicy {
+    pub day_overflow: DayOverflow,
+    pub leap_adar: LeapAdar,
+}
+
+impl Default for ArithmeticPolicy {
+    fn default() -> Self {
+        ArithmeticPolicy {
+            day_overflow: DayOverflow::Clamp,
+            leap_adar: LeapAdar::Adar2,
+        }
+    }
+}
+
+/// What to do with a yahrzeit on the 30th of Cheshvan or Kislev in a year where the month only
+/// has 29 days.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MissingThirtieth {
+    /// Always keep the yahrzeit on the 29th.
+    LastDay = 0,
+    /// Always keep the yahrzeit on the 1st of the next month.
+    NextMonth = 1,
+    /// Follow the first anniversary of the death (Magen Avraham 568:20). If the month had 30 days in
+    /// the year after the death, the yahrzeit is on the 1st of the next month when there's no 30th.
+    /// Otherwise it's always on the last day of the month.
+    FirstAnniversary = 2,
+}
+
+/// Which Adar to keep a yahrzeit in, when the death was in Adar of a regular year and the yahrzeit
+/// is in a leap year.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[repr(u8)]
+pub enum YahrzeitAdar {
+    /// Adar 1 (the Rema in 568:7).
+    Adar1 = 0,
+    /// Adar 2 (the Shulchan Aruch in 568:7).
+    Adar2 = 1,
+    /// Both, which is the custom of many Ashkenazim (Mishna Berura 568:42).
+    Both = 2,
+}
+
+/// How to find the yahrzeit of a death in a later year. See `anniversary::yahrzeit`.
+///
+/// A death in Adar 1 or Adar 2 is always remembered in Adar in a regular year, and in the same Adar
+/// in a leap year. The 30th of Adar 1 becomes the 30th of Shvat in a regular year, as both are the
+/// first day of Rosh Chodesh Adar.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub struct YahrzeitRules {
+    pub missing_thirtieth: MissingThirtieth,
+    pub leap_adar: YahrzeitAdar,
+}
 
-    BaChaH,
-    BaShaZ,
-    GaKaZ,
-    HaChA,
-    HaShaG,
-    ZaChaG,
-    ZaShaH,
+impl YahrzeitRules {
+    /// The Ashkenazi custom: follow the first anniversary for a missing 30th, and keep a death in
+    /// Adar in Adar 1 of a leap year. This is the default.
+    pub const fn ashkenazi() -> YahrzeitRules {
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::FirstAnniversary,
+            leap_adar: YahrzeitAdar::Adar1,
+        }
+    }
+
+    /// The Sephardi custom: follow the first anniversary for a missing 30th, and keep a death in
+    /// Adar in Adar 2 of a leap year.
+    pub const fn sephardi() -> YahrzeitRules {
+        YahrzeitRules {
+            missing_thirtieth: MissingThirtieth::FirstAnniversary,
+            leap_adar: YahrzeitAdar::Adar2,
+        }
+    }
+}
+
+impl Default for YahrzeitRules {
+    fn default() -> Self {
+        YahrzeitRules::ashkenazi()
+    }
+}
+
+/// When a Hebrew day starts, when converting to and from a local time.
+///
+/// Hebrew days start in the evening, but when exactly depends on who you ask.
+#[cfg(all(feature = "std", feature = "chrono"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayBoundary {
+    /// The day starts at a fixed local time on the evening before, such as 6:00 PM.
+    Time(NaiveTime),
+    /// The day starts at civil midnight, so a Hebrew date is matched to the civil day its daytime
+    /// falls out on.
+    Midnight,
+    /// The day starts at sunset at the given location. Positive latitudes are north and positive
+    /// longitudes are east.
+    Sunset { latitude: f64, longitude: f64 },
 }
@@ -0,0 +1,374 @@
+use alloc::borrow::Cow;
+use core::num::NonZeroI8;
+
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+
+#[cfg(all(feature = "std", feature = "chrono"))]
+use chrono::TimeZone;
+
+#[cfg(all(feature = "std", feature = "chrono"))]
+use crate::candle_lighting::{candle_lighting, CandleEvent, CandleLightingOptions};
+#[cfg(all(feature = "std", feature = "chrono"))]
+use crate::convert::HebrewDateRange;
+use crate::convert::{HebrewDate, HebrewYear};
+#[cfg(all(feature = "std", feature = "chrono"))]
+use crate::prelude::ConversionError;
+use crate::prelude::{Day, HebrewMonth, Location};
+#[cfg(all(feature = "std", feature = "chrono"))]
+use crate::zmanim::{zmanim, Coordinates, DepressionAngles, Zmanim};
+
+/// Whether a place had a wall around it since the days of Yehoshua, which decides when Purim is.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Walled {
+    /// Purim is on the 14th of Adar.
+    No = 0,
+    /// Purim is on the 15th of Adar (Shushan Purim).
+    Yes = 1,
+    /// It isn't known, so Purim is kept on both days (such as in Tzfat and Tiberias).
+    Doubtful = 2,
+}
+
+/// A place where someone keeps the calendar.
+///
+/// Positive latitudes are north and positive longitudes are east. The elevation is in meters above
+/// sea level. The time zone is an IANA time zone name (such as "Asia/Jerusalem"), for use with a
+/// time zone library such as chrono-tz.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+/// use heca_lib::{hebrew_date, HebrewYear};
+///
+/// let jerusalem = Place::jerusalem();
+/// assert_eq!(jerusalem.location(), Location::Israel);
+/// assert_eq!(jerusalem.purim(HebrewYear::new(5780)?).as_slice(), &[hebrew_date!(5780, Adar, 15)]);
+/// assert_eq!(Place::new_york().location(), Location::Chul);
+///
+/// let home = Place {
+///     name: "Kiryas Joel".into(),
+///     latitude: 41.342,
+///     longitude: -74.168,
+///     elevation: 180.0,
+///     time_zone: "America/New_York".into(),
+///     walled: Walled::No,
+///     in_israel: false,
+/// };
+/// assert_eq!(home.purim(HebrewYear::new(5779)?).as_slice(), &[hebrew_date!(5779, Adar2, 14)]);
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Place {
+    pub name: Cow<'static, str>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: f64,
+    pub time_zone: Cow<'static, str>,
+    pub walled: Walled,
+    pub in_israel: bool,
+}
+
+impl Place {
+    /// Returns whether this place is in Israel or in the Diaspora, which decides which Torah
+    /// readings and days of Yom Tov it has.
+    pub fn location(&self) -> Location {
+        if self.in_israel {
+            Location::Israel
+        } else {
+            Location::Chul
+        }
+    }
+
+    /// Returns the coordinates of this place, for calculating zmanim.
+    #[cfg(all(feature = "std", feature = "chrono"))]
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates {
+            latitude: self.latitude,
+            longitude: self.longitude,
+            elevation: self.elevation,
+        }
+    }
+
+    /// Returns the days on which Purim is kept in this place in a given year (in Adar 2 in a leap
+    /// year).
+    ///
+    /// This is the day of Purim itself, even when the 15th of Adar is on Shabbos. The Megillah
+    /// isn't read on Shabbos, so on those years (Purim Meshulash) walled cities read it on the 14th
+    /// instead - see `megillah_reading`.
+    pub fn purim(&self, year: HebrewYear) -> SmallVec<[HebrewDate; 2]> {
+        let day = |day| purim_month_day(year, day);
+        match self.walled {
+            Walled::No => smallvec![day(14)],
+            Walled::Yes => smallvec![day(15)],
+            Walled::Doubtful => smallvec![day(14), day(15)],
+        }
+    }
+
+    /// Returns the days on which the Megillah is read in this place in a given year.
+    ///
+    /// This is the same as `purim`, except when the 15th of Adar is on Shabbos (Purim Meshulash).
+    /// Then walled cities read the Megillah on Friday, the 14th, and places where it's doubtful
+    /// only read it on the 14th.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::{hebrew_date, HebrewYear};
+    ///
+    /// // The 15th of Adar 5781 was on Shabbos.
+    /// let year = HebrewYear::new(5781)?;
+    /// assert_eq!(Place::jerusalem().purim(year).as_slice(), &[hebrew_date!(5781, Adar, 15)]);
+    /// assert_eq!(Place::jerusalem().megillah_reading(year).as_slice(), &[hebrew_date!(5781, Adar, 14)]);
+    /// assert_eq!(Place::tzfat().megillah_reading(year).as_slice(), &[hebrew_date!(5781, Adar, 14)]);
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    pub fn megillah_reading(&self, year: HebrewYear) -> SmallVec<[HebrewDate; 2]> {
+        let mut days = self.purim(year);
+        if purim_month_day(year, 15).weekday() == Day::Shabbos {
+            days = smallvec![purim_month_day(year, 14)];
+        }
+        days
+    }
+
+    /// Returns the zmanim of a Hebrew date in this place, like `zmanim::zmanim` with the
+    /// coordinates of this place.
+    ///
+    /// `tz` is the time zone to return them in. It should be the time zone named by `time_zone`,
+    /// but this crate doesn't include a time zone database, so it has to be given separately.
+    ///
+    /// # Error Values
+    /// The same as `zmanim::zmanim`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use chrono::prelude::*;
+    /// use heca_lib::hebrew_date;
+    /// use heca_lib::prelude::*;
+    /// use heca_lib::zmanim::DepressionAngles;
+    ///
+    /// let israel_time = FixedOffset::east(3 * 3600);
+    /// let day = Place::jerusalem().zmanim(hebrew_date!(5778, Elul, 29), &israel_time, DepressionAngles::default())?;
+    /// assert!(day.sunset.unwrap() > israel_time.ymd(2018, 9, 9).and_hms(18, 50, 0));
+    /// # Ok::<(),ConversionError>(())
+    /// ```
+    #[cfg(all(feature = "std", feature = "chrono"))]
+    pub fn zmanim<Tz: TimeZone>(
+        &self,
+        date: HebrewDate,
+        tz: &Tz,
+        angles: DepressionAngles,
+    ) -> Result<Zmanim<Tz>, ConversionError> {
+        zmanim(date, self.coordinates(), tz, angles)
+    }
+
+    /// Returns every candle lighting and havdalah in a range of days in this place, like
+    /// `candle_lighting::candle_lighting` with the location and the coordinates of this place.
+    ///
+    /// `tz` is the time zone to return the times in, as in `zmanim`.
+    ///
+    /// # Error Values
+    /// The same as `candle_lighting::candle_lighting`.
+    #[cfg(all(feature = "std", feature = "chrono"))]
+    pub fn candle_lighting<Tz: TimeZone>(
+        &self,
+        days: HebrewDateRange,
+        tz: &Tz,
+        options: CandleLightingOptions,
+    ) -> Result<Vec<CandleEvent<Tz>>, ConversionError> {
+        candle_lighting(days, self.location(), self.coordinates(), tz, options)
+    }
+
+    /// Returns every place in the built-in table.
+    pub fn all() -> &'static [Place] {
+        PLACES
+    }
+}
+
+//Returns a day in the month of Purim (Adar 2 in a leap year).
+fn purim_month_day(year: HebrewYear, day: i8) -> HebrewDate {
+    let month = if year.is_leap_year() {
+        HebrewMonth::Adar2
+    } else {
+        HebrewMonth::Adar
+    };
+    //Adar and Adar 2 always have 29 days.
+    year.get_hebrew_date(month, NonZeroI8::new(day).unwrap())
+        .unwrap()
+}
+
+impl From<&Place> for Location {
+    fn from(place: &Place) -> Location {
+        place.location()
+    }
+}
+
+//Builds a const fn for every place, and a table of all of them.
+macro_rules! places {
+    ($($fn_name:ident: $name:expr, $latitude:expr, $longitude:expr, $elevation:expr, $time_zone:expr, $walled:ident, $in_israel:expr;)*) => {
+        impl Place {
+            $(
+                #[doc = concat!("Returns ", $name, ".")]
+                pub const fn $fn_name() -> Place {
+                    Place {
+                        name: Cow::Borrowed($name),
+                        latitude: $latitude,
+                        longitude: $longitude,
+                        elevation: $elevation,
+                        time_zone: Cow::Borrowed($time_zone),
+                        walled: Walled::$walled,
+                        in_israel: $in_israel,
+                    }
+                }
+            )*
+        }
+
+        static PLACES: &[Place] = &[$(Place::$fn_name()),*];
+    };
+}
+
+places! {
+    jerusalem: "Jerusalem", 31.778, 35.2354, 754.0, "Asia/Jerusalem", Yes, true;
+    tel_aviv: "Tel Aviv", 32.0853, 34.7818, 5.0, "Asia/Jerusalem", No, true;
+    haifa: "Haifa", 32.794, 34.9896, 30.0, "Asia/Jerusalem", No, true;
+    bnei_brak: "Bnei Brak", 32.0807, 34.8338, 30.0, "Asia/Jerusalem", No, true;
+    beit_shemesh: "Beit Shemesh", 31.747, 34.9881, 300.0, "Asia/Jerusalem", No, true;
+    tzfat: "Tzfat", 32.9646, 35.496, 900.0, "Asia/Jerusalem", Doubtful, true;
+    tiberias: "Tiberias", 32.7922, 35.5312, -200.0, "Asia/Jerusalem", Doubtful, true;
+    hebron: "Hebron", 31.5326, 35.0998, 930.0, "Asia/Jerusalem", Doubtful, true;
+    new_york: "New York", 40.7128, -74.006, 10.0, "America/New_York", No, false;
+    lakewood: "Lakewood", 40.0821, -74.2097, 20.0, "America/New_York", No, false;
+    baltimore: "Baltimore", 39.2904, -76.6122, 30.0, "America/New_York", No, false;
+    miami: "Miami", 25.7617, -80.1918, 2.0, "America/New_York", No, false;
+    chicago: "Chicago", 41.8781, -87.6298, 180.0, "America/Chicago", No, false;
+    los_angeles: "Los Angeles", 34.0522, -118.2437, 90.0, "America/Los_Angeles", No, false;
+    toronto: "Toronto", 43.6532, -79.3832, 76.0, "America/Toronto", No, false;
+    montreal: "Montreal", 45.5017, -73.5673, 30.0, "America/Toronto", No, false;
+    mexico_city: "Mexico City", 19.4326, -99.1332, 2240.0, "America/Mexico_City", No, false;
+    buenos_aires: "Buenos Aires", -34.6037, -58.3816, 25.0, "America/Argentina/Buenos_Aires", No, false;
+    london: "London", 51.5074, -0.1278, 11.0, "Europe/London", No, false;
+    manchester: "Manchester", 53.4808, -2.2426, 38.0, "Europe/London", No, false;
+    paris: "Paris", 48.8566, 2.3522, 35.0, "Europe/Paris", No, false;
+    antwerp: "Antwerp", 51.2194, 4.4025, 8.0, "Europe/Brussels", No, false;
+    moscow: "Moscow", 55.7558, 37.6173, 156.0, "Europe/Moscow", No, false;
+    johannesburg: "Johannesburg", -26.2041, 28.0473, 1753.0, "Africa/Johannesburg", No, false;
+    melbourne: "Melbourne", -37.8136, 144.9631, 31.0, "Australia/Melbourne", No, false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_places() {
+        assert_eq!(Place::all().len(), 25);
+        for place in Place::all() {
+            assert!((-90.0..=90.0).contains(&place.latitude), "{}", place.name);
+            assert!(
+                (-180.0..=180.0).contains(&place.longitude),
+                "{}",
+                place.name
+            );
+            assert_eq!(
+                place.in_israel,
+                place.time_zone == "Asia/Jerusalem",
+                "{}",
+                place.name
+            );
+            assert_eq!(Location::from(place), place.location());
+            let other_places = Place::all().iter().filter(|x| x.name == place.name);
+            assert_eq!(other_places.count(), 1);
+        }
+        assert_eq!(Place::all()[0], Place::jerusalem());
+    }
+
+    #[test]
+    fn megillah_isnt_read_on_shabbos() {
+        let mut meshulash_years = 0;
+        for year in 5700..5900 {
+            let year = HebrewYear::new(year).unwrap();
+            let fourteenth = Place::new_york().purim(year)[0];
+            let meshulash = Place::jerusalem().purim(year)[0].weekday() == Day::Shabbos;
+            for place in Place::all() {
+                let reading = place.megillah_reading(year);
+                if meshulash {
+                    assert_eq!(reading.as_slice(), &[fourteenth]);
+                } else {
+                    assert_eq!(reading, place.purim(year));
+                }
+            }
+            if meshulash {
+                meshulash_years += 1;
+            }
+        }
+        assert!(meshulash_years > 0);
+    }
+
+    #[cfg(all(feature = "std", feature = "chrono"))]
+    #[test]
+    fn place_functions_match_coordinates() {
+        use crate::candle_lighting::candle_lighting;
+        use crate::zmanim::zmanim;
+        use chrono::FixedOffset;
+
+        let tz = FixedOffset::east(2 * 3600);
+        let days = HebrewDateRange::new(hebrew_date!(5780, Nissan, 1), hebrew_date!(5780, Iyar, 1));
+        for place in Place::all() {
+            let date = hebrew_date!(5780, Nissan, 15);
+            assert_eq!(
+                place
+                    .zmanim(date, &tz, DepressionAngles::default())
+                    .unwrap()
+                    .sunset,
+                zmanim(date, place.coordinates(), &tz, DepressionAngles::default())
+                    .unwrap()
+                    .sunset
+            );
+            let times = |events: Vec<CandleEvent<FixedOffset>>| {
+                events.iter().map(|x| (x.day, x.time)).collect::<Vec<_>>()
+            };
+            let options = CandleLightingOptions::default();
+            assert_eq!(
+                times(place.candle_lighting(days.clone(), &tz, options).unwrap()),
+                times(
+                    candle_lighting(
+                        days.clone(),
+                        place.location(),
+                        place.coordinates(),
+                        &tz,
+                        options
+                    )
+                    .unwrap()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn purim_depends_on_the_walls() {
+        let year = HebrewYear::new(5779).unwrap();
+        assert_eq!(
+            Place::new_york().purim(year).as_slice(),
+            &[hebrew_date!(5779, Adar2, 14)]
+        );
+        assert_eq!(
+            Place::jerusalem().purim(year).as_slice(),
+            &[hebrew_date!(5779, Adar2, 15)]
+        );
+        assert_eq!(
+            Place::tzfat().purim(year).as_slice(),
+            &[hebrew_date!(5779, Adar2, 14), hebrew_date!(5779, Adar2, 15)]
+        );
+        for place in Place::all() {
+            for day in place.purim(year).iter() {
+                assert!(year
+                    .get_holidays(place.location(), &[crate::prelude::TorahReadingType::Chol])
+                    .iter()
+                    .any(|x| x.day() == *day));
+            }
+        }
+    }
+}
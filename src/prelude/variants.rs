@@ -0,0 +1,455 @@
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+use crate::parse::normalize;
+use crate::prelude::*;
+
+const LOCALES: [Locale; 5] = [
+    Locale::Hebrew,
+    Locale::HebrewNikud,
+    Locale::Ashkenazi,
+    Locale::Sephardi,
+    Locale::English,
+];
+
+//Checks a normalized string against every localized name of x.
+fn is_localized_name<T: Localized>(x: T, s: &str) -> bool {
+    LOCALES
+        .iter()
+        .any(|locale| normalize(x.localized(*locale)) == s)
+}
+
+//Implements all() and TryFrom<u8> for a fieldless enum, and FromStr if there's a function to check
+//other names than the name of the variant. The variants have to be listed in the order of their
+//discriminants, which start from 0.
+macro_rules! variants {
+    ($name:ident, [$($variant:ident),* $(,)?]) => {
+        impl $name {
+            #[doc = concat!("Returns an iterator over every ", stringify!($name), ", in order.")]
+            pub fn all() -> impl DoubleEndedIterator<Item = $name> + ExactSizeIterator + Clone {
+                const ALL: &[$name] = &[$($name::$variant),*];
+                ALL.iter().copied()
+            }
+        }
+
+        #[doc = concat!("Returns the ", stringify!($name), " with the given discriminant (`", stringify!($name), "::X as u8`), or a ParseError if there isn't one.")]
+        impl TryFrom<u8> for $name {
+            type Error = ConversionError;
+            fn try_from(id: u8) -> Result<$name, ConversionError> {
+                $name::all()
+                    .nth(usize::from(id))
+                    .ok_or(ConversionError::ParseError)
+            }
+        }
+    };
+    ($name:ident, [$($variant:ident),* $(,)?], $is_name:expr) => {
+        variants!($name, [$($variant),*]);
+
+        #[doc = concat!("Parses the name of a ", stringify!($name), ", ignoring case, spaces and punctuation. This accepts the name of the variant as well as its localized names.")]
+        impl FromStr for $name {
+            type Err = ConversionError;
+            fn from_str(s: &str) -> Result<$name, ConversionError> {
+                let s = normalize(s);
+                let is_name: fn($name, &str) -> bool = $is_name;
+                $name::all()
+                    .zip([$(stringify!($variant)),*].iter())
+                    .find(|(x, variant)| normalize(variant) == s || is_name(*x, &s))
+                    .map(|(x, _)| x)
+                    .ok_or(ConversionError::ParseError)
+            }
+        }
+    };
+}
+
+variants!(
+    Day,
+    [Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Shabbos],
+    is_localized_name
+);
+variants!(
+    HebrewMonth,
+    [
+        Tishrei, Cheshvan, Kislev, Teves, Shvat, Adar, Adar1, Adar2, Nissan, Iyar, Sivan, Tammuz,
+        Av, Elul
+    ]
+);
+variants!(Location, [Israel, Chul], is_localized_name);
+variants!(
+    TorahReadingType,
+    [YomTov, Chol, Shabbos, SpecialParsha],
+    is_localized_name
+);
+variants!(
+    MonthSchedule,
+    [
+        BaChaG, BaShaH, GaChaH, HaKaZ, HaShA, ZaChA, ZaShaG, BaChaH, BaShaZ, GaKaZ, HaChA, HaShaG,
+        ZaChaG, ZaShaH,
+    ],
+    is_localized_name
+);
+variants!(DayOverflow, [Clamp, Carry, Fail], |_, _| false);
+variants!(LeapAdar, [Adar1, Adar2], |_, _| false);
+variants!(
+    MissingThirtieth,
+    [LastDay, NextMonth, FirstAnniversary],
+    |_, _| false
+);
+variants!(YahrzeitAdar, [Adar1, Adar2, Both], |_, _| false);
+variants!(Walled, [No, Yes, Doubtful], |_, _| false);
+variants!(
+    Locale,
+    [Hebrew, HebrewNikud, Ashkenazi, Sephardi, English],
+    |_, _| false
+);
+variants!(
+    YomTov,
+    [
+        RoshHashanah1,
+        RoshHashanah2,
+        YomKippur,
+        Sukkos1,
+        Sukkos2,
+        Sukkos3,
+        Sukkos4,
+        Sukkos5,
+        Sukkos6,
+        Sukkos7,
+        ShminiAtzeres,
+        SimchasTorah,
+        Pesach1,
+        Pesach2,
+        Pesach3,
+        Pesach4,
+        Pesach5,
+        Pesach6,
+        Pesach7,
+        Pesach8,
+        Shavuos1,
+        Shavuos2,
+    ],
+    is_localized_name
+);
+variants!(
+    SpecialParsha,
+    [Shekalim, Zachor, Parah, HaChodesh],
+    is_localized_name
+);
+variants!(
+    Chol,
+    [
+        TzomGedalia,
+        RoshChodeshCheshvan1,
+        RoshChodeshCheshvan2,
+        Chanukah1,
+        Chanukah2,
+        Chanukah3,
+        Chanukah4,
+        Chanukah5,
+        Chanukah6,
+        Chanukah7,
+        Chanukah8,
+        TenTeves,
+        RoshChodeshShvat,
+        RoshChodeshNissan,
+        RoshChodeshIyar1,
+        RoshChodeshIyar2,
+        RoshChodeshSivan,
+        RoshChodeshTammuz1,
+        RoshChodeshTammuz2,
+        RoshChodeshAv,
+        RoshChodeshElul1,
+        RoshChodeshElul2,
+        RoshChodeshKislev1,
+        RoshChodeshKislev2,
+        RoshChodeshKislev,
+        RoshChodeshTeves1,
+        RoshChodeshTeves2,
+        RoshChodeshTeves,
+        RoshChodeshAdar1,
+        RoshChodeshAdar2,
+        TaanisEsther,
+        Purim,
+        ShushanPurim,
+        RoshChodeshAdarRishon1,
+        RoshChodeshAdarRishon2,
+        RoshChodeshAdarSheni1,
+        RoshChodeshAdarSheni2,
+        SeventeenTammuz,
+        NineAv,
+    ],
+    is_localized_name
+);
+variants!(
+    Parsha,
+    [
+        Vayelech,
+        Haazinu,
+        Bereishis,
+        Noach,
+        LechLecha,
+        Vayeira,
+        ChayeiSara,
+        Toldos,
+        Vayetzei,
+        Vayishlach,
+        Vayeshev,
+        Miketz,
+        Vayigash,
+        Vayechi,
+        Shemos,
+        Vaeira,
+        Bo,
+        Beshalach,
+        Yisro,
+        Mishpatim,
+        Terumah,
+        Tetzaveh,
+        KiSisa,
+        VayakhelPikudei,
+        Vayakhel,
+        Pikudei,
+        Vayikra,
+        Tzav,
+        Shemini,
+        TazriyaMetzorah,
+        Tazriya,
+        Metzorah,
+        AchareiMosKedoshim,
+        AchareiMos,
+        Kedoshim,
+        Emor,
+        BeharBechukosai,
+        Behar,
+        Bechukosai,
+        Bamidbar,
+        Naso,
+        Behaaloscha,
+        Shlach,
+        Korach,
+        ChukasBalak,
+        Chukas,
+        Balak,
+        Pinchas,
+        MatosMaasei,
+        Matos,
+        Maasei,
+        Devarim,
+        Vaeschanan,
+        Eikev,
+        Reeh,
+        Shoftim,
+        KiSeitzei,
+        KiSavoh,
+        NitzavimVayelech,
+        Nitzavim,
+    ],
+    is_localized_name
+);
+
+//The first id of every kind of TorahReading. Each kind has room for more variants, so that adding
+//one doesn't change the ids of the others.
+const YOM_TOV_IDS: u8 = 0;
+const CHOL_IDS: u8 = 32;
+const PARSHA_IDS: u8 = 96;
+const SPECIAL_PARSHA_IDS: u8 = 192;
+
+impl TorahReading {
+    /// Returns an iterator over every TorahReading: every YomTov, then every Chol, every Parsha
+    /// and every SpecialParsha.
+    pub fn all() -> impl Iterator<Item = TorahReading> + Clone {
+        YomTov::all()
+            .map(TorahReading::YomTov)
+            .chain(Chol::all().map(TorahReading::Chol))
+            .chain(Parsha::all().map(TorahReading::Shabbos))
+            .chain(SpecialParsha::all().map(TorahReading::SpecialParsha))
+    }
+
+    /// Returns a number which stays the same between versions, for storing a TorahReading. Use
+    /// `TorahReading::try_from` to get it back.
+    ///
+    /// The id is the discriminant of the inner enum, plus 0 for a YomTov, 32 for a Chol, 96 for a
+    /// Parsha and 192 for a SpecialParsha.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use heca_lib::prelude::*;
+    ///
+    /// let reading = TorahReading::Shabbos(Parsha::Noach);
+    /// assert_eq!(reading.id(), 99);
+    /// assert_eq!(TorahReading::try_from(99), Ok(reading));
+    /// assert_eq!(TorahReading::try_from(95), Err(ConversionError::ParseError));
+    /// ```
+    pub fn id(self) -> u8 {
+        match self {
+            TorahReading::YomTov(x) => YOM_TOV_IDS + x as u8,
+            TorahReading::Chol(x) => CHOL_IDS + x as u8,
+            TorahReading::Shabbos(x) => PARSHA_IDS + x as u8,
+            TorahReading::SpecialParsha(x) => SPECIAL_PARSHA_IDS + x as u8,
+        }
+    }
+}
+
+/// Returns the TorahReading with the given id (as returned by `TorahReading::id`), or a ParseError
+/// if there isn't one.
+impl TryFrom<u8> for TorahReading {
+    type Error = ConversionError;
+    fn try_from(id: u8) -> Result<TorahReading, ConversionError> {
+        match id {
+            SPECIAL_PARSHA_IDS..=u8::MAX => {
+                SpecialParsha::try_from(id - SPECIAL_PARSHA_IDS).map(TorahReading::SpecialParsha)
+            }
+            PARSHA_IDS..=u8::MAX => Parsha::try_from(id - PARSHA_IDS).map(TorahReading::Shabbos),
+            CHOL_IDS..=u8::MAX => Chol::try_from(id - CHOL_IDS).map(TorahReading::Chol),
+            _ => YomTov::try_from(id - YOM_TOV_IDS).map(TorahReading::YomTov),
+        }
+    }
+}
+
+/// Parses the name of a YomTov, a Chol, a Parsha or a SpecialParsha, in the same way as their own
+/// FromStr implementations.
+///
+/// # Examples:
+///
+/// ```
+/// use heca_lib::prelude::*;
+///
+/// assert_eq!("Ki Tavo".parse(), Ok(TorahReading::Shabbos(Parsha::KiSavoh)));
+/// assert_eq!("zachor".parse(), Ok(TorahReading::SpecialParsha(SpecialParsha::Zachor)));
+/// assert_eq!("Fast of Esther".parse(), Ok(TorahReading::Chol(Chol::TaanisEsther)));
+/// ```
+impl FromStr for TorahReading {
+    type Err = ConversionError;
+    fn from_str(s: &str) -> Result<TorahReading, ConversionError> {
+        s.parse()
+            .map(TorahReading::YomTov)
+            .or_else(|_| s.parse().map(TorahReading::Chol))
+            .or_else(|_| s.parse().map(TorahReading::Shabbos))
+            .or_else(|_| s.parse().map(TorahReading::SpecialParsha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    fn check<T>(all: Vec<T>)
+    where
+        T: Copy + Eq + core::fmt::Debug + FromStr<Err = ConversionError>,
+        T: TryFrom<u8, Error = ConversionError> + Into<u8>,
+    {
+        for (i, x) in all.iter().enumerate() {
+            assert_eq!((*x).into(), i as u8);
+            assert_eq!(T::try_from(i as u8), Ok(*x));
+            let name = format!("{:?}", x);
+            assert_eq!(name.parse(), Ok(*x));
+            assert_eq!(name.to_uppercase().parse(), Ok(*x));
+        }
+        assert_eq!(
+            T::try_from(all.len() as u8),
+            Err(ConversionError::ParseError)
+        );
+        assert_eq!(T::try_from(u8::MAX), Err(ConversionError::ParseError));
+        assert_eq!("".parse::<T>(), Err(ConversionError::ParseError));
+        assert_eq!("Not a name".parse::<T>(), Err(ConversionError::ParseError));
+    }
+
+    fn check_localized<T: Copy + Eq + core::fmt::Debug + FromStr + Localized>(all: Vec<T>) {
+        for x in all {
+            for locale in LOCALES.iter() {
+                assert_eq!(x.localized(*locale).parse::<T>().ok(), Some(x));
+            }
+        }
+    }
+
+    macro_rules! into_u8 {
+        ($($name:ident),*) => {
+            $(impl From<$name> for u8 {
+                fn from(x: $name) -> u8 {
+                    x as u8
+                }
+            })*
+        };
+    }
+    into_u8!(
+        Day,
+        Location,
+        TorahReadingType,
+        MonthSchedule,
+        DayOverflow,
+        LeapAdar,
+        MissingThirtieth,
+        YahrzeitAdar,
+        Walled,
+        Locale,
+        YomTov,
+        SpecialParsha,
+        Chol,
+        Parsha
+    );
+
+    #[test]
+    fn ids_and_names_round_trip() {
+        check(Day::all().collect());
+        check(Location::all().collect());
+        check(TorahReadingType::all().collect());
+        check(MonthSchedule::all().collect());
+        check(DayOverflow::all().collect());
+        check(LeapAdar::all().collect());
+        check(MissingThirtieth::all().collect());
+        check(YahrzeitAdar::all().collect());
+        check(Walled::all().collect());
+        check(Locale::all().collect());
+        check(YomTov::all().collect());
+        check(SpecialParsha::all().collect());
+        check(Chol::all().collect());
+        check(Parsha::all().collect());
+
+        check_localized(Day::all().collect());
+        check_localized(Location::all().collect());
+        check_localized(TorahReadingType::all().collect());
+        check_localized(MonthSchedule::all().collect());
+        check_localized(YomTov::all().collect());
+        check_localized(SpecialParsha::all().collect());
+        check_localized(Chol::all().collect());
+        check_localized(Parsha::all().collect());
+        check_localized(TorahReading::all().collect());
+
+        for (i, month) in HebrewMonth::all().enumerate() {
+            assert_eq!(month as u8, i as u8);
+            assert_eq!(HebrewMonth::try_from(i as u8), Ok(month));
+            assert_eq!(month.to_string().parse(), Ok(month));
+        }
+        assert_eq!(HebrewMonth::try_from(14u8), Err(ConversionError::ParseError));
+        assert_eq!(TorahReading::all().count(), 22 + 39 + 60 + 4);
+        let readings = TorahReading::all().collect::<Vec<_>>();
+        for id in 0..=u8::MAX {
+            match TorahReading::try_from(id) {
+                Ok(reading) => assert_eq!(reading.id(), id),
+                Err(err) => {
+                    assert_eq!(err, ConversionError::ParseError);
+                    assert!(readings.iter().all(|x| x.id() != id));
+                }
+            }
+        }
+        for reading in readings.iter() {
+            assert_eq!(TorahReading::try_from(reading.id()), Ok(*reading));
+        }
+        assert_eq!(TorahReading::YomTov(YomTov::RoshHashanah1).id(), 0);
+        assert_eq!(TorahReading::Chol(Chol::NineAv).id(), 70);
+        assert_eq!(TorahReading::Shabbos(Parsha::Nitzavim).id(), 155);
+        assert_eq!(
+            TorahReading::SpecialParsha(SpecialParsha::HaChodesh).id(),
+            195
+        );
+        for reading in TorahReading::all() {
+            assert_eq!(reading.to_string().parse(), Ok(reading));
+        }
+        assert_eq!("shabbat".parse(), Ok(Day::Shabbos));
+        assert_eq!("Diaspora".parse(), Ok(Location::Chul));
+    }
+}
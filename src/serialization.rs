@@ -0,0 +1,350 @@
+//! Other ways of serializing dates, to be used with serde's `with` attribute.
+//!
+//! By default, a HebrewDate is serialized as its day, month and year. These modules serialize it
+//! as a string or as a number instead, which takes less space and is easier to use as a key.
+//!
+//! # Examples:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use heca_lib::prelude::*;
+//! use heca_lib::{hebrew_date, HebrewDate};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Event {
+//!     #[serde(with = "heca_lib::serialization::hebrew_date_string")]
+//!     date: HebrewDate,
+//!     #[serde(with = "heca_lib::serialization::hebrew_date_day_number")]
+//!     reminder: HebrewDate,
+//! }
+//!
+//! let event = Event {
+//!     date: hebrew_date!(5779, Tishrei, 10),
+//!     reminder: hebrew_date!(5779, Tishrei, 9),
+//! };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(json, r#"{"date":"5779-01-10","reminder":736325}"#);
+//! assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+//! ```
+
+/// Serializes a HebrewDate as a string such as "5779-01-10" (the year, the month counting from
+/// Tishrei and the day).
+///
+/// Deserializing accepts every format that `HebrewDate::from_str` does.
+pub mod hebrew_date_string {
+    use serde::de::{Deserializer, Error, Visitor};
+    use serde::ser::Serializer;
+
+    use core::fmt;
+
+    use crate::HebrewDate;
+
+    pub fn serialize<S: Serializer>(date: &HebrewDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!(
+            "{}-{:02}-{:02}",
+            date.year(),
+            date.month_number(),
+            date.day()
+        ))
+    }
+
+    struct DateVisitor;
+
+    impl<'de> Visitor<'de> for DateVisitor {
+        type Value = HebrewDate;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a Hebrew date")
+        }
+
+        fn visit_str<E: Error>(self, s: &str) -> Result<HebrewDate, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HebrewDate, D::Error> {
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+/// Serializes a HebrewDate as its day number (as returned by `HebrewDate::day_number`).
+pub mod hebrew_date_day_number {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+
+    use crate::HebrewDate;
+
+    pub fn serialize<S: Serializer>(date: &HebrewDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(date.day_number())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HebrewDate, D::Error> {
+        HebrewDate::from_day_number(i64::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes a TorahReadingDay together with the Gregorian date of its daytime, as
+/// `"gregorian": "2018-09-19"`.
+///
+/// When deserializing, the Gregorian date is optional, but if it's there, it has to match the
+/// Hebrew date.
+///
+/// # Examples:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use heca_lib::prelude::*;
+/// use heca_lib::HebrewYear;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Reading {
+///     #[serde(with = "heca_lib::serialization::with_gregorian")]
+///     day: TorahReadingDay,
+/// }
+///
+/// let yom_kippur = HebrewYear::new(5779)?.get_holidays(Location::Chul, &[TorahReadingType::YomTov])[2];
+/// let json = serde_json::to_string(&Reading { day: yom_kippur }).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"day":{"day":{"day":10,"month":"Tishrei","year":5779},"name":{"YomTov":"YomKippur"},"gregorian":"2018-09-19"}}"#
+/// );
+/// assert_eq!(serde_json::from_str::<Reading>(&json).unwrap().day.name(), yom_kippur.name());
+/// # Ok::<(),ConversionError>(())
+/// ```
+#[cfg(feature = "chrono")]
+pub mod with_gregorian {
+    use chrono::NaiveDate;
+    use serde::de::{Deserializer, Error, Visitor};
+    use serde::ser::{self, SerializeStruct, Serializer};
+    use serde::Deserialize;
+
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use crate::prelude::{TorahReading, TorahReadingDay};
+    use crate::HebrewDate;
+
+    pub fn serialize<S: Serializer>(
+        day: &TorahReadingDay,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("TorahReadingDay", 3)?;
+        state.serialize_field("day", &day.day())?;
+        state.serialize_field("name", &day.name())?;
+        let gregorian = NaiveDate::try_from(day.day()).map_err(<S::Error as ser::Error>::custom)?;
+        state.serialize_field("gregorian", &format_args!("{}", gregorian))?;
+        state.end()
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "TorahReadingDay")]
+    struct Fields {
+        day: HebrewDate,
+        name: TorahReading,
+        #[serde(default)]
+        gregorian: Option<Gregorian>,
+    }
+
+    struct Gregorian(NaiveDate);
+
+    struct GregorianVisitor;
+
+    impl<'de> Visitor<'de> for GregorianVisitor {
+        type Value = Gregorian;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a date such as 2018-09-19")
+        }
+
+        fn visit_str<E: Error>(self, s: &str) -> Result<Gregorian, E> {
+            s.parse().map(Gregorian).map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Gregorian {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Gregorian, D::Error> {
+            deserializer.deserialize_str(GregorianVisitor)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TorahReadingDay, D::Error> {
+        let fields = Fields::deserialize(deserializer)?;
+        if let Some(Gregorian(gregorian)) = fields.gregorian {
+            let expected = NaiveDate::try_from(fields.day).map_err(D::Error::custom)?;
+            if gregorian != expected {
+                return Err(D::Error::custom(format_args!(
+                    "The Gregorian date {} doesn't match the Hebrew date, which is on {}",
+                    gregorian, expected
+                )));
+            }
+        }
+        TorahReadingDay::checked(fields.day, fields.name).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{HebrewDate, HebrewYear};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Compact {
+        #[serde(with = "super::hebrew_date_string")]
+        string: HebrewDate,
+        #[serde(with = "super::hebrew_date_day_number")]
+        number: HebrewDate,
+    }
+
+    #[test]
+    fn dates_round_trip() {
+        for year in (1..10000).step_by(111) {
+            let hebrew_year = HebrewYear::new(year).unwrap();
+            let json = serde_json::to_string(&hebrew_year).unwrap();
+            assert_eq!(json, year.to_string());
+            assert_eq!(
+                serde_json::from_str::<HebrewYear>(&json).unwrap(),
+                hebrew_year
+            );
+            for date in hebrew_year.days() {
+                let json = serde_json::to_string(&date).unwrap();
+                assert_eq!(serde_json::from_str::<HebrewDate>(&json).unwrap(), date);
+                let compact = Compact {
+                    string: date,
+                    number: date,
+                };
+                let json = serde_json::to_string(&compact).unwrap();
+                assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), compact);
+            }
+            for month in hebrew_year.months() {
+                let molad = month.molad();
+                let json = serde_json::to_string(&molad).unwrap();
+                assert_eq!(serde_json::from_str::<Molad>(&json).unwrap(), molad);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        for json in [
+            r#"{"day":1,"month":"Adar1","year":5780}"#,
+            r#"{"day":1,"month":"Adar","year":5779}"#,
+            r#"{"day":31,"month":"Tishrei","year":5779}"#,
+            r#"{"day":0,"month":"Tishrei","year":5779}"#,
+            r#"{"day":-1,"month":"Tishrei","year":5779}"#,
+            r#"{"day":1,"month":"Tishrei","year":0}"#,
+        ]
+        .iter()
+        {
+            assert!(
+                serde_json::from_str::<HebrewDate>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+        assert!(serde_json::from_str::<HebrewYear>("0").is_err());
+        assert!(serde_json::from_str::<Molad>(
+            r#"{"day_number":0,"hours":24,"minutes":0,"chalakim":0}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Molad>(
+            r#"{"day_number":0,"hours":0,"minutes":0,"chalakim":18}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Molad>(
+            r#"{"day_number":9223372036854775807,"hours":0,"minutes":0,"chalakim":0}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Molad>(
+            r#"{"day_number":6405119470038038,"hours":23,"minutes":59,"chalakim":0}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Compact>(r#"{"string":"5780-13-30","number":0}"#).is_err());
+        assert!(
+            serde_json::from_str::<Compact>(r#"{"string":"5779-01-10","number":-2000000}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn huge_years_are_rejected() {
+        let too_large = ConversionError::YearTooLarge.to_string();
+        for json in [
+            "18446744073709551615",
+            "1000000000000",
+            r#"{"day":1,"month":"Tishrei","year":18446744073709551615}"#,
+            r#"{"day":1,"month":"Tishrei","year":1000000000000}"#,
+        ]
+        .iter()
+        {
+            let error = if json.starts_with('{') {
+                serde_json::from_str::<HebrewDate>(json).unwrap_err()
+            } else {
+                serde_json::from_str::<HebrewYear>(json).unwrap_err()
+            };
+            assert!(error.to_string().starts_with(&too_large), "{}", json);
+        }
+        for json in [
+            r#"{"string":"18446744073709551615-01-10","number":0}"#,
+            r#"{"string":"5779-01-10","number":9223372036854775807}"#,
+        ]
+        .iter()
+        {
+            let error = serde_json::from_str::<Compact>(json).unwrap_err();
+            assert!(error.to_string().starts_with(&too_large), "{}", json);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn torah_reading_days_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct WithGregorian {
+            #[serde(with = "super::with_gregorian")]
+            day: TorahReadingDay,
+        }
+        let types = [
+            TorahReadingType::YomTov,
+            TorahReadingType::Chol,
+            TorahReadingType::Shabbos,
+            TorahReadingType::SpecialParsha,
+        ];
+        for (year, location) in
+            (5770..5790).flat_map(|x| vec![(x, Location::Chul), (x, Location::Israel)])
+        {
+            let year = HebrewYear::new(year).unwrap();
+            for day in year.get_holidays(location, &types).iter() {
+                let json = serde_json::to_string(day).unwrap();
+                let back = serde_json::from_str::<TorahReadingDay>(&json).unwrap();
+                assert_eq!((back.day(), back.name()), (day.day(), day.name()));
+
+                let json = serde_json::to_string(&WithGregorian { day: *day }).unwrap();
+                let back = serde_json::from_str::<WithGregorian>(&json).unwrap();
+                assert_eq!((back.day.day(), back.day.name()), (day.day(), day.name()));
+            }
+        }
+        let json = r#"{"day":{"day":{"day":10,"month":"Tishrei","year":5779},"name":{"YomTov":"YomKippur"}}}"#;
+        assert!(serde_json::from_str::<WithGregorian>(json).is_ok());
+        let json = r#"{"day":{"day":{"day":10,"month":"Tishrei","year":5779},"name":{"YomTov":"YomKippur"},"gregorian":"2018-09-18"}}"#;
+        assert!(serde_json::from_str::<WithGregorian>(json).is_err());
+
+        //Yom Kippur isn't on the 1st of Nissan.
+        let json =
+            r#"{"day":{"day":1,"month":"Nissan","year":5779},"name":{"YomTov":"YomKippur"}}"#;
+        assert!(serde_json::from_str::<TorahReadingDay>(json).is_err());
+        let json = format!(r#"{{"day":{}}}"#, json);
+        assert!(serde_json::from_str::<WithGregorian>(&json).is_err());
+        //Only Israel reads Acharei Mos on the 22nd of Nissan 5779.
+        let json =
+            r#"{"day":{"day":22,"month":"Nissan","year":5779},"name":{"Shabbos":"AchareiMos"}}"#;
+        assert!(serde_json::from_str::<TorahReadingDay>(json).is_ok());
+
+        //Serializing a day which chrono can't hold is an error rather than a panic.
+        let far_future = HebrewYear::new(300_000)
+            .unwrap()
+            .get_holidays(Location::Chul, &[TorahReadingType::YomTov])[0];
+        assert!(serde_json::to_string(&WithGregorian { day: far_future }).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+//! Calculating zmanim (halachic times) for a day at a location.
+//!
+//! Sunrise and sunset are calculated with the NOAA solar calculator, which is accurate to about a
+//! minute for latitudes between +/- 72 degrees, and are adjusted for the elevation of the location.
+//! Alos, misheyakir and tzeis are when the sun is a given amount of degrees below the horizon,
+//! without accounting for elevation.
+//!
+//! The day of the Gra is from sunrise to sunset, and the day of the Magen Avraham is from 72
+//! minutes before sunrise to 72 minutes after sunset. A halachic hour (sha'ah zmanis) is a twelfth
+//! of the day.
+//!
+//! # Examples:
+//!
+//! ```
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//! use heca_lib::hebrew_date;
+//! use heca_lib::zmanim::{zmanim, Coordinates, DepressionAngles};
+//!
+//! let new_york = Coordinates {
+//!     latitude: 40.7128,
+//!     longitude: -74.006,
+//!     elevation: 0.0,
+//! };
+//! let new_york_time = FixedOffset::west(4 * 3600);
+//! // The 18th of Sivan 5779 was the 21st of June, 2019.
+//! let day = zmanim(hebrew_date!(5779, Sivan, 18), new_york, &new_york_time, DepressionAngles::default())?;
+//! // Rounded to the nearest minute.
+//! let time = |x: Option<DateTime<FixedOffset>>| (x.unwrap() + Duration::seconds(30)).format("%H:%M").to_string();
+//! assert_eq!(time(day.sunrise), "05:25");
+//! assert_eq!(time(day.sof_zman_shema_gra), "09:11");
+//! assert_eq!(time(day.sunset), "20:31");
+//! # Ok::<(),heca_lib::prelude::ConversionError>(())
+//! ```
+
+use chrono::prelude::*;
+use chrono::Duration;
+
+use crate::convert::sun::{time_at_zenith, SUNSET_ZENITH};
+use crate::convert::HebrewDate;
+use crate::prelude::ConversionError;
+
+//The radius of the earth in meters, as used for the elevation adjustment.
+const EARTH_RADIUS: f64 = 6_356_900.0;
+
+/// A place on earth. Positive latitudes are north and positive longitudes are east. The elevation
+/// is in meters above sea level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: f64,
+}
+
+/// How many degrees below the horizon the sun is at alos, misheyakir and tzeis.
+///
+/// The default is 16.1 degrees for alos, 11.5 degrees for misheyakir and 8.5 degrees for tzeis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepressionAngles {
+    pub alos: f64,
+    pub misheyakir: f64,
+    pub tzeis: f64,
+}
+
+impl Default for DepressionAngles {
+    fn default() -> Self {
+        DepressionAngles {
+            alos: 16.1,
+            misheyakir: 11.5,
+            tzeis: 8.5,
+        }
+    }
+}
+
+/// The zmanim of a day. A zman is None if the sun doesn't get to the position it depends on (such as
+/// near the poles, or for alos and tzeis in the summer in the far north).
+#[derive(Debug, Clone)]
+pub struct Zmanim<Tz: TimeZone> {
+    pub alos: Option<DateTime<Tz>>,
+    pub misheyakir: Option<DateTime<Tz>>,
+    pub sunrise: Option<DateTime<Tz>>,
+    pub sof_zman_shema_mga: Option<DateTime<Tz>>,
+    pub sof_zman_shema_gra: Option<DateTime<Tz>>,
+    pub sof_zman_tefila_mga: Option<DateTime<Tz>>,
+    pub sof_zman_tefila_gra: Option<DateTime<Tz>>,
+    pub chatzos: Option<DateTime<Tz>>,
+    pub mincha_gedola: Option<DateTime<Tz>>,
+    pub mincha_ketana: Option<DateTime<Tz>>,
+    pub plag_hamincha: Option<DateTime<Tz>>,
+    pub sunset: Option<DateTime<Tz>>,
+    pub tzeis: Option<DateTime<Tz>>,
+}
+
+// Returns the time which is a given amount of halachic hours into a day.
+fn hours_into(
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    hours: f64,
+) -> Option<DateTime<Utc>> {
+    let (start, end) = (start?, end?);
+    let length = (end - start).num_milliseconds() as f64;
+    Some(start + Duration::milliseconds((length * hours / 12.0).round() as i64))
+}
+
+/// Returns the zmanim of the daytime of a Hebrew date, at a given location, in a given time zone.
+///
+/// # Arguments
+///
+/// `date` - The Hebrew date. The zmanim are on the civil day of its daytime.
+///
+/// `coordinates` - Where to calculate the zmanim for.
+///
+/// `tz` - The time zone to return the zmanim in.
+///
+/// `angles` - How many degrees below the horizon the sun is at alos, misheyakir and tzeis.
+///
+/// # Error Values
+/// * `YearTooLarge` - The date is after the last date chrono supports.
+///
+/// # Examples:
+///
+/// ```
+/// use chrono::prelude::*;
+/// use heca_lib::hebrew_date;
+/// use heca_lib::zmanim::{zmanim, Coordinates, DepressionAngles};
+///
+/// let jerusalem = Coordinates {
+///     latitude: 31.778,
+///     longitude: 35.2354,
+///     elevation: 0.0,
+/// };
+/// let israel_time = FixedOffset::east(3 * 3600);
+/// let day = zmanim(hebrew_date!(5778, Elul, 29), jerusalem, &israel_time, DepressionAngles::default())?;
+/// assert_eq!(day.sunset.unwrap().format("%H:%M").to_string(), "18:53");
+///
+/// // Tzeis at 7.083 degrees, instead of 8.5 degrees.
+/// let angles = DepressionAngles {
+///     tzeis: 7.083,
+///     ..DepressionAngles::default()
+/// };
+/// let earlier = zmanim(hebrew_date!(5778, Elul, 29), jerusalem, &israel_time, angles)?;
+/// assert!(earlier.tzeis.unwrap() < day.tzeis.unwrap());
+/// # Ok::<(),heca_lib::prelude::ConversionError>(())
+/// ```
+pub fn zmanim<Tz: TimeZone>(
+    date: HebrewDate,
+    coordinates: Coordinates,
+    tz: &Tz,
+    angles: DepressionAngles,
+) -> Result<Zmanim<Tz>, ConversionError> {
+    let civil_date = date.to_civil_date()?;
+    let Coordinates {
+        latitude,
+        longitude,
+        elevation,
+    } = coordinates;
+    let at =
+        |zenith: f64, rising: bool| time_at_zenith(civil_date, latitude, longitude, zenith, rising);
+    //From higher up, the sun can be seen when it's further below the horizon.
+    let elevation_adjustment = (EARTH_RADIUS / (EARTH_RADIUS + elevation.max(0.0)))
+        .acos()
+        .to_degrees();
+    let sunrise = at(SUNSET_ZENITH + elevation_adjustment, true);
+    let sunset = at(SUNSET_ZENITH + elevation_adjustment, false);
+    let mga_start = sunrise.map(|x| x - Duration::minutes(72));
+    let mga_end = sunset.map(|x| x + Duration::minutes(72));
+    let in_tz = |x: Option<DateTime<Utc>>| x.map(|x| x.with_timezone(tz));
+    Ok(Zmanim {
+        alos: in_tz(at(90.0 + angles.alos, true)),
+        misheyakir: in_tz(at(90.0 + angles.misheyakir, true)),
+        sunrise: in_tz(sunrise),
+        sof_zman_shema_mga: in_tz(hours_into(mga_start, mga_end, 3.0)),
+        sof_zman_shema_gra: in_tz(hours_into(sunrise, sunset, 3.0)),
+        sof_zman_tefila_mga: in_tz(hours_into(mga_start, mga_end, 4.0)),
+        sof_zman_tefila_gra: in_tz(hours_into(sunrise, sunset, 4.0)),
+        chatzos: in_tz(hours_into(sunrise, sunset, 6.0)),
+        mincha_gedola: in_tz(hours_into(sunrise, sunset, 6.5)),
+        mincha_ketana: in_tz(hours_into(sunrise, sunset, 9.5)),
+        plag_hamincha: in_tz(hours_into(sunrise, sunset, 10.75)),
+        sunset: in_tz(sunset),
+        tzeis: in_tz(at(90.0 + angles.tzeis, false)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEW_YORK: Coordinates = Coordinates {
+        latitude: 40.7128,
+        longitude: -74.006,
+        elevation: 0.0,
+    };
+
+    fn assert_close<Tz: TimeZone>(time: Option<DateTime<Tz>>, hour: u32, minute: u32) {
+        let time = time.unwrap();
+        let expected = time.date().and_hms(hour, minute, 0);
+        assert!(
+            (time.clone() - expected).num_seconds().abs() <= 90,
+            "{:?} isn't close to {}:{:02}",
+            time,
+            hour,
+            minute
+        );
+    }
+
+    #[test]
+    fn zmanim_match_published_tables() {
+        let eastern_daylight = FixedOffset::west(4 * 3600);
+        let day = zmanim(
+            hebrew_date!(5779, Sivan, 18),
+            NEW_YORK,
+            &eastern_daylight,
+            DepressionAngles::default(),
+        )
+        .unwrap();
+        assert_close(day.alos, 3, 36);
+        assert_close(day.sunrise, 5, 25);
+        assert_close(day.sof_zman_shema_mga, 8, 35);
+        assert_close(day.sof_zman_shema_gra, 9, 11);
+        assert_close(day.sof_zman_tefila_gra, 10, 27);
+        assert_close(day.chatzos, 12, 58);
+        assert_close(day.mincha_gedola, 13, 36);
+        assert_close(day.plag_hamincha, 18, 56);
+        assert_close(day.sunset, 20, 31);
+        assert_close(day.tzeis, 21, 21);
+
+        let eastern_standard = FixedOffset::west(5 * 3600);
+        let day = zmanim(
+            hebrew_date!(5780, Kislev, 23),
+            NEW_YORK,
+            &eastern_standard,
+            DepressionAngles::default(),
+        )
+        .unwrap();
+        assert_close(day.sunrise, 7, 17);
+        assert_close(day.chatzos, 11, 54);
+        assert_close(day.sunset, 16, 32);
+    }
+
+    #[test]
+    fn zmanim_are_in_order() {
+        let mut date = hebrew_date!(5780, Tishrei, 1);
+        for _ in 0..400 {
+            let day = zmanim(date, NEW_YORK, &Utc, DepressionAngles::default()).unwrap();
+            let times = [
+                day.alos,
+                day.misheyakir,
+                day.sunrise,
+                day.sof_zman_shema_mga,
+                day.sof_zman_shema_gra,
+                day.sof_zman_tefila_gra,
+                day.chatzos,
+                day.mincha_gedola,
+                day.mincha_ketana,
+                day.plag_hamincha,
+                day.sunset,
+                day.tzeis,
+            ];
+            for pair in times.windows(2) {
+                assert!(pair[0].unwrap() < pair[1].unwrap(), "{:?}", day);
+            }
+            assert!(day.sof_zman_tefila_mga.unwrap() < day.sof_zman_tefila_gra.unwrap());
+            date = date.checked_add_days(1).unwrap();
+        }
+    }
+
+    #[test]
+    fn elevation_makes_the_day_longer() {
+        let date = hebrew_date!(5780, Nissan, 1);
+        let sea_level = zmanim(date, NEW_YORK, &Utc, DepressionAngles::default()).unwrap();
+        let mountain = Coordinates {
+            elevation: 800.0,
+            ..NEW_YORK
+        };
+        let high_up = zmanim(date, mountain, &Utc, DepressionAngles::default()).unwrap();
+        assert!(high_up.sunrise.unwrap() < sea_level.sunrise.unwrap());
+        assert!(high_up.sunset.unwrap() > sea_level.sunset.unwrap());
+        assert_eq!(high_up.tzeis, sea_level.tzeis);
+    }
+
+    #[test]
+    fn no_zmanim_near_the_poles() {
+        let svalbard = Coordinates {
+            latitude: 78.22,
+            longitude: 15.65,
+            elevation: 0.0,
+        };
+        //The 18th of Sivan 5779 was the 21st of June, 2019, and the 23rd of Kislev 5780 was the 21st
+        //of December.
+        for date in [
+            hebrew_date!(5779, Sivan, 18),
+            hebrew_date!(5780, Kislev, 23),
+        ]
+        .iter()
+        {
+            let day = zmanim(*date, svalbard, &Utc, DepressionAngles::default()).unwrap();
+            assert_eq!(day.sunrise, None);
+            assert_eq!(day.sof_zman_shema_gra, None);
+            assert_eq!(day.sunset, None);
+        }
+    }
+
+    #[test]
+    fn dates_past_chrono_are_errors() {
+        let date = hebrew_date!(300000, Tishrei, 1);
+        assert_eq!(
+            zmanim(date, NEW_YORK, &Utc, DepressionAngles::default()).unwrap_err(),
+            ConversionError::YearTooLarge
+        );
+    }
+}